@@ -3,33 +3,1285 @@
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as Rbac;
+use codec::Decode;
 use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{EnsureOrigin, Get, Hooks, IsType},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, StaticLookup, TrailingZeroInput};
+use sp_std::vec;
+
+fn max_name<T: Config>() -> RoleName<T> {
+	BoundedVec::try_from(vec![b'n'; T::MaxNameLength::get() as usize]).unwrap()
+}
+
+fn max_metadata<T: Config>() -> RoleMetadata<T> {
+	BoundedVec::try_from(vec![b'm'; T::MaxMetadataLength::get() as usize]).unwrap()
+}
+
+fn new_role<T: Config>() -> RoleId {
+	let role = NextRoleId::<T>::get();
+	Roles::<T>::insert(role, RoleInfo::new(max_name::<T>(), max_metadata::<T>()));
+	NextRoleId::<T>::put(role + 1);
+	role
+}
+
+fn grant_permissions<T: Config>(role: RoleId, count: u32) {
+	for i in 0..count {
+		RolePermissions::<T>::insert(role, Permission::new((i >> 8) as u8, i as u8), ());
+	}
+	Roles::<T>::mutate(role, |info| info.as_mut().unwrap().permissions = count);
+}
+
+/// `count` distinct permissions of calls of the pallet `pallet_index`.
+fn template_permissions<T: Config>(pallet_index: u8, count: u32) -> TemplatePermissions<T> {
+	BoundedVec::truncate_from((0..count).map(|i| Permission::new(pallet_index, i as u8)).collect())
+}
+
+/// Create a role template with `count` permissions no role was granted by `grant_permissions`.
+fn new_template<T: Config>(count: u32) -> TemplateId {
+	let template = NextTemplateId::<T>::get();
+	Templates::<T>::insert(
+		template,
+		RoleTemplate {
+			name: max_name::<T>(),
+			permissions: template_permissions::<T>(u8::MAX, count),
+			deny_rules: BoundedVec::default(),
+			revision: 0,
+			derived: 0,
+		},
+	);
+	NextTemplateId::<T>::put(template + 1);
+	template
+}
+
+/// Give `account` enough funds for every deposit it may be charged.
+fn fund<T: Config>(account: &T::AccountId) {
+	let amount = BalanceOf::<T>::max_value() / 4u32.into();
+	let _ = <T::Currency as fungible::Unbalanced<_>>::increase_balance(
+		account,
+		amount,
+		Precision::BestEffort,
+	);
+}
+
+/// The parts of the policy of a role a deposit may be held for along with `permission`.
+fn permission_deposits(permission: Permission) -> [DepositKind; 3] {
+	[
+		DepositKind::Permission(permission),
+		DepositKind::Constraints(permission),
+		DepositKind::Condition(permission),
+	]
+}
+
+/// Hold a deposit from a funded account for each of the parts `kinds` of the policy of `role`.
+fn hold_deposits<T: Config>(role: RoleId, kinds: impl IntoIterator<Item = DepositKind>) {
+	let depositor: T::AccountId = account("depositor", 0, 0);
+	fund::<T>(&depositor);
+	for kind in kinds {
+		Rbac::<T>::hold_deposit(Some(&depositor), role, kind, T::ConstraintDeposit::get()).unwrap();
+	}
+}
+
+fn hold_role<T: Config>(account: &T::AccountId, role: RoleId) {
+	AccountRoles::<T>::insert(account, role, RoleAssignment::default());
+	RoleMembers::<T>::insert(role, account, ());
+	AccountRoleCount::<T>::mutate(account, |count| *count += 1);
+	Roles::<T>::mutate(role, |info| info.as_mut().unwrap().members += 1);
+}
+
+/// Make `count` accounts holders of `role`.
+fn add_holders<T: Config>(role: RoleId, count: u32) {
+	for i in 0..count {
+		hold_role::<T>(&account("holder", i, 0), role);
+	}
+}
+
+/// Make `senior` inherit `junior`.
+fn link<T: Config>(senior: RoleId, junior: RoleId) {
+	RoleJuniors::<T>::insert(senior, junior, ());
+	RoleSeniors::<T>::insert(junior, senior, ());
+}
+
+/// Create `levels` levels of `MaxJuniorsPerRole` roles below a new role, each role inheriting
+/// every role of the next level, and return the new role and a role of the last level.
+fn new_hierarchy<T: Config>(levels: u32) -> (RoleId, RoleId) {
+	let top = new_role::<T>();
+	let mut level = vec![top];
+	for _ in 0..levels {
+		let next: Vec<_> = (0..T::MaxJuniorsPerRole::get()).map(|_| new_role::<T>()).collect();
+		for upper in &level {
+			for lower in &next {
+				link::<T>(*upper, *lower);
+			}
+		}
+		level = next;
+	}
+	(top, level[0])
+}
+
+/// Make `role` mutually exclusive with a new role under a static constraint.
+fn constrain<T: Config>(role: RoleId) {
+	let exclusive = new_role::<T>();
+	let constraint = NextConstraintId::<T>::get();
+	let roles = BoundedVec::try_from(vec![role, exclusive]).unwrap();
+	SodConstraints::<T>::insert(
+		constraint,
+		SodConstraint { roles, max_held: 1, kind: SodKind::Static },
+	);
+	RoleConstraints::<T>::insert(role, constraint, ());
+	RoleConstraints::<T>::insert(exclusive, constraint, ());
+	NextConstraintId::<T>::put(constraint + 1);
+}
+
+/// Delegate the whole of `role` to `delegate` through a chain of `MaxDelegationDepth`
+/// delegations backed by an assignment.
+fn delegate_chain<T: Config>(role: RoleId, delegate: &T::AccountId) {
+	let max_depth = T::MaxDelegationDepth::get();
+	let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	let mut delegator: T::AccountId = account("chain", role, 0);
+	hold_role::<T>(&delegator, role);
+	let mut parent = None;
+	for depth in 1..=max_depth {
+		let to = if depth == max_depth { delegate.clone() } else { account("chain", role, depth) };
+		let delegation = NextDelegationId::<T>::get();
+		Delegations::<T>::insert(
+			delegation,
+			Delegation {
+				delegator: delegator.clone(),
+				delegate: to.clone(),
+				role,
+				permissions: None,
+				parent,
+				depth,
+				expires_at,
+			},
+		);
+		DelegationsBy::<T>::insert(&delegator, delegation, ());
+		DelegationsTo::<T>::insert(&to, delegation, ());
+		RoleDelegations::<T>::insert(role, delegation, ());
+		DelegationExpiries::<T>::insert(expires_at, delegation, ());
+		NextDelegationId::<T>::put(delegation + 1);
+		parent = Some(delegation);
+		delegator = to;
+	}
+}
+
+/// A distinct attribute key of maximum length.
+fn attribute_key<T: Config>(i: u32) -> AttributeKey<T> {
+	let mut key = vec![b'k'; T::MaxAttributeKeyLength::get() as usize];
+	key.iter_mut().zip(i.to_le_bytes()).for_each(|(k, b)| *k = b);
+	BoundedVec::truncate_from(key)
+}
+
+fn attribute_value<T: Config>() -> AttributeValue<T> {
+	BoundedVec::truncate_from(vec![b'v'; T::MaxAttributeValueLength::get() as usize])
+}
+
+/// A condition of `n` operations that never holds, well formed if `n` is odd.
+///
+/// It reads the balance of the caller, then looks through every attribute of the caller for a key
+/// it does not have as many times as it can.
+fn max_condition<T: Config>(n: u32) -> Condition<T> {
+	let predicate = ConditionOp::AttributeEquals {
+		key: attribute_key::<T>(u32::MAX),
+		value: attribute_value::<T>(),
+	};
+	let ops = (0..n)
+		.map(|i| match i {
+			0 => ConditionOp::FreeBalanceAbove(BalanceOf::<T>::max_value()),
+			i if i % 2 == 1 => predicate.clone(),
+			_ => ConditionOp::Or,
+		})
+		.collect::<Vec<_>>();
+	Condition(BoundedVec::truncate_from(ops))
+}
+
+/// Keep `count` snapshots of the policy, the latest of the current version.
+fn take_snapshots<T: Config>(count: u32) {
+	let version = CurrentPolicyVersion::<T>::get().max(count);
+	let snapshots = (version + 1 - count..=version).map(|version| (version, max_name::<T>()));
+	Snapshots::<T>::put(SnapshotsOf::<T>::truncate_from(snapshots.collect()));
+	CurrentPolicyVersion::<T>::put(version);
+}
+
+/// Make the whitelisted caller an admin of every role through the bootstrap admin role, and take
+/// a snapshot of the policy so that the changes the caller makes are recorded.
+///
+/// The caller has as many roles active as it may, each above the widest hierarchy allowed, and
+/// the bootstrap admin role is at the bottom of the hierarchy of the role its session yields
+/// last, so that checking whether the caller administers a role walks every hierarchy.
+fn admin_caller<T: Config>() -> T::AccountId {
+	take_snapshots::<T>(1);
+	let caller: T::AccountId = whitelisted_caller();
+	fund::<T>(&caller);
+	let hierarchies = (0..T::MaxRolesPerAccount::get())
+		.map(|_| new_hierarchy::<T>(T::MaxHierarchyDepth::get()))
+		.collect::<Vec<_>>();
+	for (top, _) in &hierarchies {
+		hold_role::<T>(&caller, *top);
+		SessionRoles::<T>::insert(&caller, top, ());
+	}
+	let last = SessionRoles::<T>::iter_key_prefix(&caller).last().unwrap();
+	let (_, admin) = hierarchies.into_iter().find(|(top, _)| *top == last).unwrap();
+	BootstrapAdmin::<T>::put(admin);
+	caller
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
+	fn create_role() {
+		take_snapshots::<T>(1);
 		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		create_role(RawOrigin::Signed(caller), max_name::<T>(), max_metadata::<T>());
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert!(Roles::<T>::contains_key(0));
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn update_role() {
+		let role = new_role::<T>();
+		Rbac::<T>::set_owner(role, Some(&account("owner", 0, 0))).unwrap();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		update_role(RawOrigin::Signed(caller), role, max_name::<T>(), max_metadata::<T>());
+
+		assert!(Roles::<T>::contains_key(role));
+	}
+
+	#[benchmark]
+	fn delete_role(p: Linear<0, { T::MaxPermissionsPerRole::get() }>) {
+		let role = new_role::<T>();
+		grant_permissions::<T>(role, p);
+		let permissions = RolePermissions::<T>::iter_key_prefix(role).collect::<Vec<_>>();
+		hold_deposits::<T>(
+			role,
+			permissions.into_iter().flat_map(permission_deposits).chain([DepositKind::Role]),
+		);
+		Rbac::<T>::set_owner(role, Some(&account("owner", 0, 0))).unwrap();
+		Rbac::<T>::set_pending_owner(role, Some(&account("owner", 1, 0))).unwrap();
+		GrantApprovals::<T>::insert(role, GrantApproval { approver: role, threshold: 1 });
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		delete_role(RawOrigin::Signed(caller), role);
+
+		assert!(!Roles::<T>::contains_key(role));
+		assert!(!RoleOwners::<T>::contains_key(role));
+		assert_eq!(RolePermissions::<T>::iter_prefix(role).count(), 0);
+	}
+
+	#[benchmark]
+	fn grant_permission() {
+		let role = new_role::<T>();
+		grant_permissions::<T>(role, T::MaxPermissionsPerRole::get() - 1);
+		let permission = Permission::new(u8::MAX, u8::MAX);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		grant_permission(RawOrigin::Signed(caller), role, permission);
+
+		assert!(RolePermissions::<T>::contains_key(role, permission));
+	}
+
+	#[benchmark]
+	fn revoke_permission() {
+		let role = new_role::<T>();
+		grant_permissions::<T>(role, T::MaxPermissionsPerRole::get());
+		let permission = Permission::new(0, 0);
+		hold_deposits::<T>(role, permission_deposits(permission));
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		revoke_permission(RawOrigin::Signed(caller), role, permission);
+
+		assert!(!RolePermissions::<T>::contains_key(role, permission));
+	}
+
+	#[benchmark]
+	fn link_roles(d: Linear<1, { T::MaxHierarchyDepth::get() }>, h: Linear<0, 1_000>) {
+		// `d - 1` levels of `MaxJuniorsPerRole` roles below `junior`, so the new link reaches the
+		// maximum depth through as many paths as possible.
+		let (junior, bottom) = new_hierarchy::<T>(d - 1);
+		// The bottom of the hierarchy is constrained, so every holder of `senior` is checked.
+		constrain::<T>(bottom);
+		let senior = new_role::<T>();
+		add_holders::<T>(senior, h);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		link_roles(RawOrigin::Signed(caller), senior, junior, h);
+
+		assert!(RoleJuniors::<T>::contains_key(senior, junior));
+	}
+
+	#[benchmark]
+	fn unlink_roles() {
+		let senior = new_role::<T>();
+		let junior = new_role::<T>();
+		RoleJuniors::<T>::insert(senior, junior, ());
+		RoleSeniors::<T>::insert(junior, senior, ());
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		unlink_roles(RawOrigin::Signed(caller), senior, junior);
+
+		assert!(!RoleJuniors::<T>::contains_key(senior, junior));
+	}
+
+	#[benchmark]
+	fn assign_role() {
+		// Every role the member holds or is delegated, at the maximum delegation depth, and the
+		// assigned role inherit the widest hierarchy allowed, whose roles are all constrained, so
+		// that checking separation of duty walks it from each of them.
+		let (base, _) = new_hierarchy::<T>(T::MaxHierarchyDepth::get() - 1);
+		for role in Rbac::<T>::inherited_roles(base) {
+			constrain::<T>(role);
+		}
+		let inheriting_base = || {
+			let role = new_role::<T>();
+			link::<T>(role, base);
+			role
+		};
+		let member: T::AccountId = account("member", 0, 0);
+		for _ in 1..T::MaxRolesPerAccount::get() {
+			hold_role::<T>(&member, inheriting_base());
+		}
+		for _ in 0..T::MaxDelegationsPerAccount::get() {
+			delegate_chain::<T>(inheriting_base(), &member);
+		}
+		let role = inheriting_base();
+		let lookup = T::Lookup::unlookup(member.clone());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		assign_role(RawOrigin::Signed(caller), lookup, role, Some(expires_at));
+
+		assert!(AccountRoles::<T>::contains_key(&member, role));
+		assert!(RoleExpiries::<T>::contains_key(expires_at, (&member, role)));
+	}
+
+	#[benchmark]
+	fn revoke_role() {
+		let role = new_role::<T>();
+		let member: T::AccountId = account("member", 0, 0);
+		let lookup = T::Lookup::unlookup(member.clone());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let caller = admin_caller::<T>();
+		Rbac::<T>::assign_role(
+			RawOrigin::Signed(caller.clone()).into(),
+			lookup.clone(),
+			role,
+			Some(expires_at),
+		)
+		.unwrap();
+		#[extrinsic_call]
+		revoke_role(RawOrigin::Signed(caller), lookup, role);
+
+		assert!(!AccountRoles::<T>::contains_key(&member, role));
+		assert!(!RoleExpiries::<T>::contains_key(expires_at, (&member, role)));
+	}
+
+	#[benchmark]
+	fn expire_role() {
+		let role = new_role::<T>();
+		let member: T::AccountId = account("member", 0, 0);
+		let lookup = T::Lookup::unlookup(member.clone());
+		let now = frame_system::Pallet::<T>::block_number();
+		let expires_at = now + 1u32.into();
+		let caller = admin_caller::<T>();
+		Rbac::<T>::assign_role(RawOrigin::Signed(caller).into(), lookup, role, Some(expires_at))
+			.unwrap();
+		ExpiryCursor::<T>::put(expires_at);
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(expires_at, Weight::MAX);
+		}
+
+		assert!(!AccountRoles::<T>::contains_key(&member, role));
+	}
+
+	#[benchmark]
+	fn set_role_admin() {
+		let role = new_role::<T>();
+		let admin = new_role::<T>();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_role_admin(RawOrigin::Signed(caller), role, Some(admin));
+
+		assert_eq!(Roles::<T>::get(role).unwrap().admin, Some(admin));
+	}
+
+	#[benchmark]
+	fn set_bootstrap_admin() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		take_snapshots::<T>(1);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(role));
+
+		assert_eq!(BootstrapAdmin::<T>::get(), Some(role));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_sod_constraint(
+		r: Linear<2, { T::MaxRolesPerConstraint::get() }>,
+		h: Linear<0, 1_000>,
+	) -> Result<(), BenchmarkError> {
+		let roles: Vec<RoleId> = (0..r).map(|_| new_role::<T>()).collect();
+		add_holders::<T>(roles[0], h);
+		let roles = ConstraintRoles::<T>::try_from(roles).unwrap();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, roles, r - 1, SodKind::Dynamic, h);
+
+		assert!(SodConstraints::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_sod_constraint() -> Result<(), BenchmarkError> {
+		let roles: Vec<RoleId> =
+			(0..T::MaxRolesPerConstraint::get()).map(|_| new_role::<T>()).collect();
+		let roles = ConstraintRoles::<T>::try_from(roles).unwrap();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Rbac::<T>::add_sod_constraint(origin.clone(), roles, 1, SodKind::Static, 0).unwrap();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert!(!SodConstraints::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn activate_role() {
+		// The role is covered by a dynamic constraint, so the session of the caller, full of the
+		// widest hierarchies, is checked along with the roles delegated to the caller, which
+		// receives as many delegations as it may at the maximum depth.
+		let role = new_role::<T>();
+		let exclusive = new_role::<T>();
+		let roles = BoundedVec::try_from(vec![role, exclusive]).unwrap();
+		SodConstraints::<T>::insert(
+			0,
+			SodConstraint { roles, max_held: 1, kind: SodKind::Dynamic },
+		);
+		RoleConstraints::<T>::insert(role, 0, ());
+		RoleConstraints::<T>::insert(exclusive, 0, ());
+		let caller = admin_caller::<T>();
+		hold_role::<T>(&caller, role);
+		for _ in 0..T::MaxDelegationsPerAccount::get() {
+			delegate_chain::<T>(new_role::<T>(), &caller);
+		}
+		#[extrinsic_call]
+		activate_role(RawOrigin::Signed(caller.clone()), role);
+
+		assert!(SessionRoles::<T>::contains_key(&caller, role));
+	}
+
+	#[benchmark]
+	fn deactivate_role() {
+		let role = new_role::<T>();
+		let caller = admin_caller::<T>();
+		hold_role::<T>(&caller, role);
+		SessionRoles::<T>::insert(&caller, role, ());
+		#[extrinsic_call]
+		deactivate_role(RawOrigin::Signed(caller.clone()), role);
+
+		assert!(!SessionRoles::<T>::contains_key(&caller, role));
+	}
+
+	#[benchmark]
+	fn delegate_role(d: Linear<1, { T::MaxDelegationDepth::get() }>) {
+		// A chain of `d - 1` delegations ending with the caller, so the new delegation reaches
+		// the maximum depth. Every role involved inherits the widest hierarchy allowed, whose
+		// roles are all constrained, so that checking separation of duty walks it from each of
+		// them.
+		let (base, _) = new_hierarchy::<T>(T::MaxHierarchyDepth::get() - 1);
+		for role in Rbac::<T>::inherited_roles(base) {
+			constrain::<T>(role);
+		}
+		let inheriting_base = || {
+			let role = new_role::<T>();
+			link::<T>(role, base);
+			role
+		};
+		let role = inheriting_base();
+		grant_permissions::<T>(role, T::MaxPermissionsPerRole::get());
+		let caller: T::AccountId = whitelisted_caller();
+		let mut delegator: T::AccountId = account("delegator", 0, 0);
+		hold_role::<T>(&delegator, role);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let mut parent = None;
+		for i in 1..d {
+			let delegate = if i == d - 1 { caller.clone() } else { account("delegator", i, 0) };
+			Rbac::<T>::delegate_role(
+				RawOrigin::Signed(delegator).into(),
+				T::Lookup::unlookup(delegate.clone()),
+				role,
+				None,
+				expires_at,
+				parent,
+			)
+			.unwrap();
+			parent = Some(NextDelegationId::<T>::get() - 1);
+			delegator = delegate;
+		}
+		if d == 1 {
+			hold_role::<T>(&caller, role);
+		}
+		// The delegate holds as many roles as it may, all active, and already receives as many
+		// effective delegations as it may but one, at the maximum depth, so that every chain it
+		// receives is checked.
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		for _ in 0..T::MaxRolesPerAccount::get() {
+			let held = inheriting_base();
+			hold_role::<T>(&delegate, held);
+			SessionRoles::<T>::insert(&delegate, held, ());
+		}
+		for _ in 1..T::MaxDelegationsPerAccount::get() {
+			delegate_chain::<T>(inheriting_base(), &delegate);
+		}
+		let lookup = T::Lookup::unlookup(delegate.clone());
+		let permissions = DelegatedPermissions::<T>::try_from(
+			(0..T::MaxPermissionsPerRole::get())
+				.map(|i| Permission::new((i >> 8) as u8, i as u8))
+				.collect::<Vec<_>>(),
+		)
+		.unwrap();
+		#[extrinsic_call]
+		delegate_role(
+			RawOrigin::Signed(caller),
+			lookup,
+			role,
+			Some(permissions),
+			expires_at,
+			parent,
+		);
+
+		assert!(DelegationsTo::<T>::iter_key_prefix(&delegate).next().is_some());
+	}
+
+	#[benchmark]
+	fn revoke_delegation() {
+		// An admin of the role, rather than the delegator, revokes the delegation.
+		let role = new_role::<T>();
+		let delegator: T::AccountId = account("delegator", 0, 0);
+		hold_role::<T>(&delegator, role);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Rbac::<T>::delegate_role(
+			RawOrigin::Signed(delegator).into(),
+			T::Lookup::unlookup(delegate),
+			role,
+			None,
+			expires_at,
+			None,
+		)
+		.unwrap();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		revoke_delegation(RawOrigin::Signed(caller), 0);
+
+		assert!(!Delegations::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn expire_delegation() {
+		let role = new_role::<T>();
 		let caller: T::AccountId = whitelisted_caller();
+		hold_role::<T>(&caller, role);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		Rbac::<T>::delegate_role(
+			RawOrigin::Signed(caller).into(),
+			T::Lookup::unlookup(delegate),
+			role,
+			None,
+			expires_at,
+			None,
+		)
+		.unwrap();
+		ExpiryCursor::<T>::put(expires_at);
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(expires_at, Weight::MAX);
+		}
+
+		assert!(!Delegations::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn set_max_members() {
+		let role = new_role::<T>();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_max_members(RawOrigin::Signed(caller), role, Some(1));
+
+		assert_eq!(Roles::<T>::get(role).unwrap().max_members, Some(1));
+	}
+
+	#[benchmark]
+	fn set_prerequisite(m: Linear<0, 1_000>) {
+		let role = new_role::<T>();
+		let prerequisite = new_role::<T>();
+		for i in 0..m {
+			let member = account("member", i, 0);
+			hold_role::<T>(&member, prerequisite);
+			hold_role::<T>(&member, role);
+		}
+		let caller = admin_caller::<T>();
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		set_prerequisite(RawOrigin::Signed(caller), role, Some(prerequisite), m);
+
+		assert_eq!(Roles::<T>::get(role).unwrap().prerequisite, Some(prerequisite));
+	}
+
+	#[benchmark]
+	fn add_deny_rule() {
+		let role = new_role::<T>();
+		grant_permissions::<T>(role, T::MaxPermissionsPerRole::get() - 1);
+		let permission = Permission::pallet(u8::MAX);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		add_deny_rule(RawOrigin::Signed(caller), role, permission);
+
+		assert!(RoleDenyRules::<T>::contains_key(role, permission));
+	}
+
+	#[benchmark]
+	fn remove_deny_rule() {
+		let role = new_role::<T>();
+		let permission = Permission::pallet(u8::MAX);
+		RoleDenyRules::<T>::insert(role, permission, ());
+		Roles::<T>::mutate(role, |info| info.as_mut().unwrap().deny_rules = 1);
+		hold_deposits::<T>(role, [DepositKind::DenyRule(permission)]);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		remove_deny_rule(RawOrigin::Signed(caller), role, permission);
+
+		assert!(!RoleDenyRules::<T>::contains_key(role, permission));
+	}
+
+	#[benchmark]
+	fn set_permission_constraints() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		let permission = Permission::All;
+		RolePermissions::<T>::insert(role, permission, ());
+		let constraint = ConstraintOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let constraints: PermissionConstraintsOf<T> =
+			BoundedVec::try_from(vec![constraint; T::MaxConstraintsPerPermission::get() as usize])
+				.unwrap();
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_permission_constraints(
+			RawOrigin::Signed(caller),
+			role,
+			permission,
+			constraints.clone(),
+		);
+
+		assert_eq!(PermissionConstraints::<T>::get(role, permission), Some(constraints));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_rate_limit() {
+		let role = new_role::<T>();
+		let limit = RateLimit { calls: u32::MAX, period: 1u32.into() };
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_rate_limit(RawOrigin::Signed(caller), role, Some(limit));
+
+		assert_eq!(Roles::<T>::get(role).unwrap().rate_limit, Some(limit));
+	}
+
+	#[benchmark]
+	fn expire_usage_window() {
+		let caller: T::AccountId = whitelisted_caller();
+		let permission = Permission::All;
+		let ends_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		UsageWindows::<T>::insert(&caller, permission, UsageWindow { calls: 1, ends_at });
+		UsageWindowExpiries::<T>::insert(ends_at, (&caller, permission), ());
+		ExpiryCursor::<T>::put(ends_at);
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(ends_at, Weight::MAX);
+		}
+
+		assert!(!UsageWindows::<T>::contains_key(&caller, permission));
+	}
+
+	#[benchmark]
+	fn check_access() -> Result<(), BenchmarkError> {
+		// The caller has as many roles active as it may and receives as many delegations as it
+		// may, at the maximum depth, all inheriting the widest hierarchy allowed. Only the last
+		// delegated role allows the call, under a rate limit, so that the rules of every role in
+		// scope are looked up before the call is allowed and recorded.
+		let (base, _) = new_hierarchy::<T>(T::MaxHierarchyDepth::get() - 1);
+		let inheriting_base = || {
+			let role = new_role::<T>();
+			link::<T>(role, base);
+			role
+		};
+		let caller: T::AccountId = whitelisted_caller();
+		for _ in 0..T::MaxRolesPerAccount::get() {
+			let held = inheriting_base();
+			hold_role::<T>(&caller, held);
+			SessionRoles::<T>::insert(&caller, held, ());
+		}
+		let mut role = base;
+		for _ in 0..T::MaxDelegationsPerAccount::get() {
+			role = inheriting_base();
+			delegate_chain::<T>(role, &caller);
+		}
+		let call: <T as frame_system::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call = <T as Config>::RuntimeCall::from_ref(&call);
+		let permission = Permission::from_call(call).ok_or(BenchmarkError::Weightless)?;
+		RolePermissions::<T>::insert(role, permission, ());
+		let limit = RateLimit { calls: 1, period: 1u32.into() };
+		Roles::<T>::mutate(role, |info| info.as_mut().unwrap().rate_limit = Some(limit));
+
+		#[block]
+		{
+			let (decision, _) = Rbac::<T>::check_access(&caller, call).unwrap();
+			Rbac::<T>::record_access(&caller, call, &decision);
+		}
+
+		assert!(UsageWindows::<T>::contains_key(&caller, permission));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_allowance() {
+		let role = new_role::<T>();
+		let allowance = Allowance { amount: 1u32.into(), period: 1u32.into() };
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_allowance(RawOrigin::Signed(caller), role, Some(allowance));
+
+		assert_eq!(Roles::<T>::get(role).unwrap().allowance, Some(allowance));
+	}
+
+	#[benchmark]
+	fn expire_allowance_window() {
+		let caller: T::AccountId = whitelisted_caller();
+		let role = new_role::<T>();
+		let refills_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let window = AllowanceWindow { remaining: 0u32.into(), refills_at };
+		AllowanceWindows::<T>::insert(&caller, role, window);
+		AllowanceRefills::<T>::insert(refills_at, (&caller, role), ());
+		ExpiryCursor::<T>::put(refills_at);
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(refills_at, Weight::MAX);
+		}
+
+		assert!(!AllowanceWindows::<T>::contains_key(&caller, role));
+	}
+
+	#[benchmark]
+	fn set_attribute() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = account("account", 0, 0);
+		let max_attributes = T::MaxAttributesPerAccount::get();
+		let attributes: Vec<_> = (1..max_attributes)
+			.map(|i| (attribute_key::<T>(i), attribute_value::<T>()))
+			.collect();
+		AccountAttributes::<T>::insert(
+			&account,
+			AccountAttributesOf::<T>::truncate_from(attributes),
+		);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let key = attribute_key::<T>(0);
+		let lookup = T::Lookup::unlookup(account.clone());
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, lookup, key, Some(attribute_value::<T>()));
+
+		assert_eq!(AccountAttributes::<T>::get(&account).len() as u32, max_attributes);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_permission_condition() {
+		let role = new_role::<T>();
+		let permission = Permission::All;
+		RolePermissions::<T>::insert(role, permission, ());
+		let condition = max_condition::<T>((T::MaxConditionLength::get() - 1) | 1);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		set_permission_condition(RawOrigin::Signed(caller), role, permission, Some(condition));
+
+		assert!(PermissionConditions::<T>::contains_key(role, permission));
+	}
+
+	#[benchmark]
+	fn evaluate_condition(n: Linear<1, { T::MaxConditionLength::get() }>) {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let max_attributes = T::MaxAttributesPerAccount::get();
+		let attributes: Vec<_> = (0..max_attributes)
+			.map(|i| (attribute_key::<T>(i), attribute_value::<T>()))
+			.collect();
+		AccountAttributes::<T>::insert(
+			&caller,
+			AccountAttributesOf::<T>::truncate_from(attributes),
+		);
+		let condition = max_condition::<T>(n);
+		let holds;
+
+		#[block]
+		{
+			holds = Rbac::<T>::evaluate_condition(&condition, Some(&caller));
+		}
+
+		assert!(!holds);
+	}
+
+	#[benchmark]
+	fn take_snapshot() -> Result<(), BenchmarkError> {
+		let max_snapshots = T::MaxSnapshots::get();
+		take_snapshots::<T>(max_snapshots - 1);
+		CurrentPolicyVersion::<T>::mutate(|version| *version += 1);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, max_name::<T>());
+
+		assert_eq!(Snapshots::<T>::get().len() as u32, max_snapshots);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_snapshot() -> Result<(), BenchmarkError> {
+		let max_snapshots = T::MaxSnapshots::get();
+		take_snapshots::<T>(max_snapshots);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1);
+
+		assert_eq!(Snapshots::<T>::get().len() as u32, max_snapshots - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rollback_to() {
+		take_snapshots::<T>(T::MaxSnapshots::get());
+		#[extrinsic_call]
+		_(RawOrigin::Root, 1);
+
+		assert_eq!(Snapshots::<T>::get().len(), 1);
+		assert!(PendingRollback::<T>::exists());
+	}
+
+	#[benchmark]
+	fn undo_policy_version(
+		c: Linear<0, { MaxPolicyChanges::<T>::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		let constraint = ConstraintOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let constraints: PermissionConstraintsOf<T> =
+			BoundedVec::try_from(vec![constraint; T::MaxConstraintsPerPermission::get() as usize])
+				.unwrap();
+		let condition = max_condition::<T>(T::MaxConditionLength::get());
+		for index in 0..c {
+			let change = PolicyChange::Permission {
+				role,
+				permission: Permission::new((index >> 8) as u8, index as u8),
+				granted: true,
+				constraints: Some(constraints.clone()),
+				condition: Some(condition.clone()),
+			};
+			PolicyJournal::<T>::insert(1, index, change);
+		}
+		PolicyJournalLength::<T>::insert(1, c);
+		CurrentPolicyVersion::<T>::put(1);
+		PendingRollback::<T>::put(Rollback { from: 1, to: 0, changes: 0, kept_roles: 0 });
+		let now = frame_system::Pallet::<T>::block_number();
+		ExpiryCursor::<T>::put(now + 1u32.into());
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(now, Weight::MAX);
+		}
+
+		assert!(!PendingRollback::<T>::exists());
+		assert_eq!(RolePermissions::<T>::iter_prefix(role).count() as u32, c);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_template() -> Result<(), BenchmarkError> {
+		let max_permissions = T::MaxPermissionsPerRole::get();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			max_name::<T>(),
+			template_permissions::<T>(0, max_permissions - max_permissions / 2),
+			template_permissions::<T>(1, max_permissions / 2),
+		);
+
+		assert!(Templates::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_template() -> Result<(), BenchmarkError> {
+		let max_permissions = T::MaxPermissionsPerRole::get();
+		let template = new_template::<T>(max_permissions);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			template,
+			max_name::<T>(),
+			template_permissions::<T>(0, max_permissions - max_permissions / 2),
+			template_permissions::<T>(1, max_permissions / 2),
+		);
+
+		assert_eq!(Templates::<T>::get(template).unwrap().revision, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_template() -> Result<(), BenchmarkError> {
+		let template = new_template::<T>(T::MaxPermissionsPerRole::get());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, template);
+
+		assert!(!Templates::<T>::contains_key(template));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn instantiate_template(p: Linear<0, { T::MaxPermissionsPerRole::get() }>) {
+		take_snapshots::<T>(1);
+		let template = new_template::<T>(p);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), template, max_name::<T>(), max_metadata::<T>(), true);
+
+		assert_eq!(RolePermissions::<T>::iter_prefix(0).count() as u32, p);
+		assert!(RoleTemplates::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn clone_role(p: Linear<0, { T::MaxPermissionsPerRole::get() }>) -> Result<(), BenchmarkError> {
+		let source = new_role::<T>();
+		grant_permissions::<T>(source, p);
+		let constraint = ConstraintOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let constraints: PermissionConstraintsOf<T> =
+			BoundedVec::try_from(vec![constraint; T::MaxConstraintsPerPermission::get() as usize])
+				.unwrap();
+		let condition = max_condition::<T>(T::MaxConditionLength::get());
+		for permission in RolePermissions::<T>::iter_key_prefix(source).collect::<Vec<_>>() {
+			PermissionConstraints::<T>::insert(source, permission, &constraints);
+			PermissionConditions::<T>::insert(source, permission, &condition);
+		}
+		let template = new_template::<T>(0);
+		RoleTemplates::<T>::insert(source, TemplateTracking { template, revision: 0 });
+		let approval = GrantApproval { approver: source, threshold: 1 };
+		GrantApprovals::<T>::insert(source, approval);
+		let caller = admin_caller::<T>();
+		let role = NextRoleId::<T>::get();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), source, max_name::<T>(), max_metadata::<T>());
+
+		assert_eq!(RolePermissions::<T>::iter_prefix(role).count() as u32, p);
+		assert_eq!(PermissionConditions::<T>::iter_prefix(role).count() as u32, p);
+		assert_eq!(GrantApprovals::<T>::get(role), Some(approval));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sync_role(p: Linear<0, { T::MaxPermissionsPerRole::get() }>) {
+		let role = new_role::<T>();
+		grant_permissions::<T>(role, p);
+		let permissions = RolePermissions::<T>::iter_key_prefix(role).collect::<Vec<_>>();
+		hold_deposits::<T>(role, permissions.into_iter().flat_map(permission_deposits));
+		let template = new_template::<T>(p);
+		Templates::<T>::mutate(template, |info| info.as_mut().unwrap().revision = 1);
+		RoleTemplates::<T>::insert(role, TemplateTracking { template, revision: 0 });
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), role);
+
+		assert_eq!(RoleTemplates::<T>::get(role).unwrap().revision, 1);
+		assert_eq!(Rbac::<T>::permissions_of(role).len() as u32, p);
+	}
+
+	#[benchmark]
+	fn untrack_template() {
+		let role = new_role::<T>();
+		let template = new_template::<T>(0);
+		RoleTemplates::<T>::insert(role, TemplateTracking { template, revision: 0 });
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), role);
+
+		assert!(!RoleTemplates::<T>::contains_key(role));
+	}
+
+	#[benchmark]
+	fn propose_owner() {
+		let role = new_role::<T>();
+		Rbac::<T>::set_pending_owner(role, Some(&account("owner", 1, 0))).unwrap();
+		let caller = admin_caller::<T>();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), role, Some(owner_lookup));
+
+		assert_eq!(PendingOwners::<T>::get(role), Some(owner));
+	}
+
+	#[benchmark]
+	fn accept_ownership() {
+		let role = new_role::<T>();
+		let previous: T::AccountId = account("owner", 0, 0);
+		Rbac::<T>::set_owner(role, Some(&previous)).unwrap();
+		hold_deposits::<T>(role, [DepositKind::Role]);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		Rbac::<T>::set_pending_owner(role, Some(&caller)).unwrap();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), role);
+
+		assert_eq!(RoleOwners::<T>::get(role), Some(caller.clone()));
+		assert!(Deposits::<T>::get(role, DepositKind::Role).map_or(true, |d| d.depositor == caller));
+		assert!(OwnedRoles::<T>::contains_key(&caller, role));
+		assert!(!OwnedRoles::<T>::contains_key(&previous, role));
+	}
+
+	#[benchmark]
+	fn freeze_role() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		let origin =
+			T::IncidentOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, role);
+
+		assert!(FrozenRoles::<T>::contains_key(role));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_role() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		FrozenRoles::<T>::insert(role, frame_system::Pallet::<T>::block_number());
+		let origin =
+			T::IncidentOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, role);
+
+		assert!(!FrozenRoles::<T>::contains_key(role));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_enforcement_mode() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, EnforcementMode::AuditOnly);
+
+		assert_eq!(Enforcement::<T>::get(), EnforcementMode::AuditOnly);
+	}
+
+	#[benchmark]
+	fn set_break_glass_role() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		let account: T::AccountId = account("responder", 0, 0);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, account_lookup, Some(role));
+
+		assert_eq!(BreakGlassRoles::<T>::get(&account), Some(role));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn break_glass() {
+		let role = new_role::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		BreakGlassRoles::<T>::insert(&caller, role);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(SessionRoles::<T>::contains_key(&caller, role));
+		assert_eq!(UnreviewedBreakGlass::<T>::get(&caller), Some(0));
+	}
+
+	#[benchmark]
+	fn review_break_glass() {
+		let role = new_role::<T>();
+		let account: T::AccountId = account("responder", 0, 0);
+		let now = frame_system::Pallet::<T>::block_number();
+		BreakGlassUses::<T>::insert(
+			0,
+			BreakGlassUse { account: account.clone(), role, activated_at: now, expires_at: now },
+		);
+		UnreviewedBreakGlass::<T>::insert(&account, 0);
+		let caller = admin_caller::<T>();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(!UnreviewedBreakGlass::<T>::contains_key(&account));
+	}
+
+	#[benchmark]
+	fn set_grant_approval() -> Result<(), BenchmarkError> {
+		let role = new_role::<T>();
+		let approver = new_role::<T>();
+		let approval = GrantApproval { approver, threshold: T::MaxGrantApprovals::get() };
+		GrantApprovals::<T>::insert(role, GrantApproval { approver: role, threshold: 1 });
+		take_snapshots::<T>(1);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, role, Some(approval));
+
+		assert_eq!(GrantApprovals::<T>::get(role), Some(approval));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_grant() {
+		let role = new_role::<T>();
+		let approver = new_role::<T>();
+		GrantApprovals::<T>::insert(role, GrantApproval { approver, threshold: 1 });
+		let caller = admin_caller::<T>();
+		let account: T::AccountId = account("grantee", 0, 0);
+		let account_lookup = T::Lookup::unlookup(account);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), account_lookup, role, None);
+
+		assert!(GrantProposals::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn approve_grant() {
+		let role = new_role::<T>();
+		let caller = admin_caller::<T>();
+		let approver = BootstrapAdmin::<T>::get().unwrap();
+		let threshold = T::MaxGrantApprovals::get();
+		GrantApprovals::<T>::insert(role, GrantApproval { approver, threshold });
+		let grantee: T::AccountId = account("grantee", 0, 0);
+		let approvals = (1..threshold).map(|i| account("approver", i, 0)).collect::<Vec<_>>();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		GrantProposals::<T>::insert(
+			0,
+			GrantProposal {
+				proposer: account("proposer", 0, 0),
+				account: grantee.clone(),
+				role,
+				assignment_expires_at: None,
+				approvals: BoundedVec::truncate_from(approvals),
+				expires_at,
+			},
+		);
+		GrantProposalExpiries::<T>::insert(expires_at, 0, ());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(!GrantProposals::<T>::contains_key(0));
+		assert!(AccountRoles::<T>::contains_key(&grantee, role));
+	}
+
+	#[benchmark]
+	fn reject_grant() {
+		let role = new_role::<T>();
+		let caller = admin_caller::<T>();
+		let approver = BootstrapAdmin::<T>::get().unwrap();
+		GrantApprovals::<T>::insert(role, GrantApproval { approver, threshold: 1 });
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		GrantProposals::<T>::insert(
+			0,
+			GrantProposal {
+				proposer: caller.clone(),
+				account: account("grantee", 0, 0),
+				role,
+				assignment_expires_at: None,
+				approvals: Default::default(),
+				expires_at,
+			},
+		);
+		GrantProposalExpiries::<T>::insert(expires_at, 0, ());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(!GrantProposals::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn cancel_grant() {
+		let role = new_role::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		GrantProposals::<T>::insert(
+			0,
+			GrantProposal {
+				proposer: caller.clone(),
+				account: account("grantee", 0, 0),
+				role,
+				assignment_expires_at: None,
+				approvals: Default::default(),
+				expires_at,
+			},
+		);
+		GrantProposalExpiries::<T>::insert(expires_at, 0, ());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(!GrantProposals::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn expire_grant_proposal() {
+		let role = new_role::<T>();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		GrantProposals::<T>::insert(
+			0,
+			GrantProposal {
+				proposer: whitelisted_caller(),
+				account: account("grantee", 0, 0),
+				role,
+				assignment_expires_at: None,
+				approvals: Default::default(),
+				expires_at,
+			},
+		);
+		GrantProposalExpiries::<T>::insert(expires_at, 0, ());
+		ExpiryCursor::<T>::put(expires_at);
+
+		#[block]
+		{
+			Rbac::<T>::on_idle(expires_at, Weight::MAX);
+		}
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(!GrantProposals::<T>::contains_key(0));
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Rbac, crate::mock::new_test_ext(), crate::mock::Test);
 }
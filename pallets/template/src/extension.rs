@@ -0,0 +1,126 @@
+//! Signed extension enforcing the access decisions of the RBAC pallet.
+
+use crate::{Config, EnforcementMode, Error, Event, Pallet, Permission, WeightInfo};
+use codec::{Decode, Encode};
+use frame_support::traits::{Contains, IsType, PalletInfoAccess};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchError, ModuleError,
+};
+use sp_std::marker::PhantomData;
+
+/// Rejects signed transactions whose signer is not permitted to dispatch their call, has
+/// exhausted the rate limit of the permission allowing it, or would spend more than the allowance
+/// left to it.
+///
+/// Calls are charged to rate limits and allowances when the transaction is dispatched, and the
+/// worst-case weight of the access check, along with the weight of evaluating conditions, is
+/// registered for the block.
+/// Calls of this pallet are exempt, as they authorize their origin themselves, and so are the
+/// [`Config::ExemptCalls`] of the runtime.
+///
+/// Nothing is rejected in [`EnforcementMode::AuditOnly`], where rejections are reported by an
+/// event instead, nor checked in [`EnforcementMode::Disabled`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckAccess<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckAccess<T> {
+	/// Create a new `CheckAccess` extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Whether `call` is a call of this pallet or exempted by the runtime.
+	fn is_exempt(call: &<T as Config>::RuntimeCall) -> bool {
+		let pallet = Permission::pallet(<Pallet<T> as PalletInfoAccess>::index() as u8);
+		T::ExemptCalls::contains(call) ||
+			Pallet::<T>::permission_of(call)
+				.map_or(false, |permission| pallet.covers(&permission))
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckAccess<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckAccess<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckAccess")
+	}
+}
+
+/// The transaction validity error reporting `error`, with the index of `error` as custom code.
+pub(crate) fn invalid<T: Config>(error: Error<T>) -> TransactionValidityError {
+	let code = match DispatchError::from(error) {
+		DispatchError::Module(ModuleError { error, .. }) => error[0],
+		_ => 0,
+	};
+	InvalidTransaction::Custom(code).into()
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckAccess<T> {
+	const IDENTIFIER: &'static str = "CheckAccess";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let call = <T as Config>::RuntimeCall::from_ref(call);
+		if Pallet::<T>::enforcement_mode() == EnforcementMode::Enforcing && !Self::is_exempt(call) {
+			Pallet::<T>::check_access(who, call).map_err(invalid::<T>)?;
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		let call = <T as Config>::RuntimeCall::from_ref(call);
+		let mode = Pallet::<T>::enforcement_mode();
+		if mode == EnforcementMode::Disabled || Self::is_exempt(call) {
+			return Ok(())
+		}
+		let weight = T::WeightInfo::check_access();
+		match Pallet::<T>::check_access(who, call) {
+			Ok((decision, conditions)) => {
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					weight.saturating_add(conditions),
+					info.class,
+				);
+				Pallet::<T>::record_access(who, call, &decision);
+			},
+			Err(error) if mode == EnforcementMode::AuditOnly => {
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, info.class);
+				Pallet::<T>::deposit_event(Event::AccessDenialAudited {
+					who: who.clone(),
+					permission: Pallet::<T>::permission_of(call),
+					error: error.into(),
+				})
+			},
+			Err(error) => return Err(invalid(error)),
+		}
+		Ok(())
+	}
+}
@@ -1,8 +1,141 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-/// Edit this file to define custom logic or remove it if it is not needed.
-/// Learn more about FRAME and the core library of Substrate FRAME pallets:
-/// <https://docs.substrate.io/reference/frame-pallets/>
+//! # RBAC Pallet
+//!
+//! Role based access control for the runtime.
+//!
+//! The pallet keeps a registry of roles. Each role is identified by a sequential [`RoleId`] and
+//! carries a bounded name and bounded metadata.
+//!
+//! Roles are granted [`Permission`]s. A permission identifies a single dispatchable of the runtime
+//! by the index of its pallet in `construct_runtime!` and its call index, so the permission
+//! required by any `RuntimeCall` can be computed with [`Pallet::permission_of`]. Wildcard
+//! permissions cover every call of a pallet or every call of the runtime, including calls added
+//! later. When several permissions of a role cover a call, the most specific one is reported by
+//! [`Pallet::permitting_permission`]: the call permission, then the pallet wildcard, then the
+//! runtime wildcard.
+//!
+//! Roles may also carry deny rules, for a call, a pallet or the whole runtime. A deny rule wins
+//! over any allow, whether granted directly, inherited through the hierarchy or delegated, and
+//! whichever role of the account carries it. [`Pallet::access_decision`] names the rule that
+//! decided whether a call is allowed.
+//!
+//! Permissions granted to a role may carry constraints on the arguments of the calls they cover,
+//! such as a maximum transferred value. The constraints are defined and evaluated by the runtime
+//! through [`Config::ConstraintEvaluator`], and a permission only allows calls satisfying all of
+//! its constraints.
+//!
+//! A role may also limit how many calls each account may dispatch using each of its permissions
+//! within a window of blocks. Calls are counted per account and permission when dispatched, and
+//! windows are pruned in `on_idle` once they end.
+//!
+//! Permissions may also carry a [`Condition`] on the context of the call: a boolean expression
+//! over the current block, the free balance of the caller and attributes of the caller, such as
+//! `department=ops`, which [`Config::AdminOrigin`] records per account. A permission only allows
+//! calls while its condition holds. Conditions are bounded by [`Config::MaxConditionLength`], and
+//! the weight of evaluating them is charged to the checked transaction.
+//!
+//! Roles may carry a spending allowance that refills every period, such as a budget for balance
+//! transfers. Each holder spends the allowance of the role whose permission allows the call, by
+//! the value the runtime reports through [`Config::SpendOf`], and [`Pallet::remaining_allowance`]
+//! reports what is left.
+//!
+//! The [`CheckAccess`] signed extension enforces these rules on signed transactions: a transaction
+//! is only valid if its signer is permitted to dispatch its call, within the rate limit and the
+//! remaining allowance of the permission allowing it.
+//! Root sets how it enforces them: [`EnforcementMode::Enforcing`] rejects other transactions,
+//! [`EnforcementMode::AuditOnly`] lets them through but reports them by an event, which allows
+//! rolling RBAC out on a live chain, and [`EnforcementMode::Disabled`] checks nothing.
+//!
+//! Roles are assigned to accounts. Assignments are indexed both by account and by role, so the
+//! roles of an account and the holders of a role can be listed without iterating the whole state.
+//! A role may limit how many accounts it is assigned to, and may require its holders to hold a
+//! prerequisite role for at least as long as they hold it. Both apply to delegations of the whole
+//! role as well: their delegates count as holders.
+//!
+//! Following the NIST RBAC model, holding a role is not enough to exercise it: an account activates
+//! a subset of its roles into its session, and permission and admin checks only consider the
+//! roles active in the session (and the roles they inherit).
+//!
+//! Roles form a hierarchy: a senior role inherits every permission of its junior roles,
+//! transitively. Links that would create a cycle or a chain longer than
+//! [`Config::MaxHierarchyDepth`], or give a role more than [`Config::MaxJuniorsPerRole`] junior
+//! roles, are rejected.
+//!
+//! Administration follows the ARBAC model: every role may declare an admin role, and only
+//! accounts with that admin role active in their session (or of a role inheriting it) may assign,
+//! revoke or otherwise manage it. Roles without an admin role of their own are managed by holders
+//! of the bootstrap admin role, which is set by [`Config::AdminOrigin`]. `AdminOrigin` may also
+//! manage every role directly.
+//!
+//! Separation of duty constraints limit how many roles out of a set a single account may be
+//! authorized for. Static constraints limit the roles an account holds and are enforced when roles
+//! are assigned, dynamic constraints limit the roles active together in a session and are
+//! enforced when roles are activated. Both are enforced when roles are linked, and can only be
+//! registered while no existing assignment or session violates them.
+//!
+//! Holders of a role may delegate it, or a subset of its permissions, to other accounts for a
+//! bounded period, and delegates may delegate it further up to [`Config::MaxDelegationDepth`]
+//! delegations. A delegation is only effective while every delegation up its chain and the
+//! assignment backing the original delegation are, so revoking any of them cascades to all
+//! downstream delegations. Delegated permissions are effective without being activated in a
+//! session, but do not make the delegate an admin. The roles whose permissions are delegated
+//! count towards both kinds of separation of duty constraints, so delegating, activating roles
+//! and registering constraints all take them into account, and no account may delegate to itself.
+//!
+//! The policy, made of the roles and their permissions, deny rules, constraints, conditions and
+//! required approvals, the hierarchy and the bootstrap admin role, is versioned: every change to it
+//! bumps [`Pallet::policy_version`]. [`Config::AdminOrigin`] may take named snapshots of versions,
+//! and root may roll the policy back to a snapshot. While snapshots exist, every change records
+//! what it replaces, and a rollback undoes the changes made since the snapshot in `on_idle`, one
+//! version at a time, so the policy always matches one of its past versions. Assignments are not
+//! part of the policy: roles created since the snapshot are kept while they are still assigned.
+//!
+//! Permissions, deny rules, links and assignments can also be managed in bulk with
+//! `apply_policy_batch`, which applies a bounded list of operations in order, all or none of
+//! them, with a single event and the policy changes making up a single version.
+//!
+//! Accounts pay for the state they create: a deposit is held from the signed account creating a
+//! role, granting a permission, adding a deny rule or attaching constraints or a condition to a
+//! permission, and released when that part of the policy is removed, rolled back included.
+//! Changes made by [`Config::AdminOrigin`] hold no deposit.
+//!
+//! [`Config::AdminOrigin`] may define role templates, sets of permissions and deny rules that
+//! new roles can be created from. A role created from a template, or cloned from another role,
+//! may track the template, so that later updates of the template can be propagated to it by
+//! syncing it, which makes its permissions and deny rules those of the template.
+//!
+//! The signed account creating a role owns it, up to [`Config::MaxOwnedRoles`] roles. The owner
+//! may update and delete the role alongside its admins, and transfers ownership in two steps: it
+//! proposes a new owner, which becomes the owner once it accepts and then holds the deposit for
+//! the role. When the owner account is reaped, the role is left to its admins, who may then
+//! propose a new owner, as may [`Config::AdminOrigin`] at any time.
+//!
+//! During an incident, [`Config::IncidentOrigin`] may freeze a role: until it is thawed, the role
+//! and the roles it inherits allow nothing to its holders and delegates, and it confers no admin
+//! rights, while its assignments, delegations and deny rules are kept. Every check and query
+//! reflects it, and [`AccessDecision::Frozen`] names the frozen allow.
+//!
+//! Accounts designated by [`Config::AdminOrigin`] may break the glass in an emergency: they are
+//! assigned their break-glass role, active in their session, for [`Config::BreakGlassPeriod`]
+//! blocks, and a loud event is emitted. Each use must then be reviewed by another account, an
+//! admin of the role or `AdminOrigin`, and an account may not break the glass again while one of
+//! its uses awaits review.
+//!
+//! Assigning a role that requires approvals, set by `AdminOrigin`, takes approvals from a
+//! threshold of distinct holders of its approver role: its admins propose grants, which approvers
+//! other than the proposer and the proposed account approve or reject, and the role is assigned
+//! once enough approvals are in. Proposals that are not approved within
+//! [`Config::GrantProposalLifetime`] blocks expire. Only `AdminOrigin` may assign, delegate or
+//! link such a role directly, and roles already inherited by a senior role cannot require
+//! approvals.
+//!
+//! Assignments may carry an expiry block, delegations always do. Expired assignments and
+//! delegations are ignored by every check and are removed in `on_idle`, along with ended usage
+//! and allowance windows and stale grant proposals, as long as the block has weight to spare.
+//!
+//! Learn more about FRAME and the core library of Substrate FRAME pallets:
+//! <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 
 #[cfg(test)]
@@ -13,14 +146,40 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod extension;
+pub mod traits;
+pub mod types;
 pub mod weights;
+pub use extension::*;
+pub use traits::*;
+pub use types::*;
 pub use weights::*;
 
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{
+		fungible::{self, MutateHold},
+		tokens::Precision,
+		Contains, EnsureOrigin, Get,
+	},
+	weights::Weight,
+};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
+use sp_runtime::{traits::Zero, DispatchError, Saturating};
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::StaticLookup;
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -30,79 +189,3852 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching call type. Permissions are derived from the pallet and call indices
+		/// that start the encoding of its variants.
+		type RuntimeCall: Parameter + IsType<<Self as frame_system::Config>::RuntimeCall>;
+		/// Origin allowed to manage every role and to set the bootstrap admin role.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to freeze and thaw roles during an incident.
+		type IncidentOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The enforcement mode of [`CheckAccess`] until root sets one.
+		#[pallet::constant]
+		type DefaultEnforcementMode: Get<EnforcementMode>;
+		/// Evaluates the constraints on call arguments that permissions may carry.
+		type ConstraintEvaluator: ConstraintEvaluator<<Self as Config>::RuntimeCall>;
+		/// The currency spent by role holders, and held for deposits.
+		type Currency: fungible::Inspect<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// Measures how much of the currency a call spends, to charge it to role allowances.
+		type SpendOf: SpendOf<Self::AccountId, <Self as Config>::RuntimeCall, BalanceOf<Self>>;
+		/// Calls [`CheckAccess`] lets through without checking access, such as those of
+		/// privileged pallets that must stay usable whatever the policy.
+		type ExemptCalls: Contains<<Self as Config>::RuntimeCall>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
+		/// Maximum length of a role name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// Maximum length of the metadata attached to a role.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+		/// Maximum number of permissions and deny rules of a single role.
+		#[pallet::constant]
+		type MaxPermissionsPerRole: Get<u32>;
+		/// Maximum number of constraints a single permission may carry.
+		#[pallet::constant]
+		type MaxConstraintsPerPermission: Get<u32>;
+		/// Maximum number of roles that can be assigned to a single account.
+		#[pallet::constant]
+		type MaxRolesPerAccount: Get<u32>;
+		/// Maximum number of links in any chain of senior to junior roles.
+		#[pallet::constant]
+		type MaxHierarchyDepth: Get<u32>;
+		/// Maximum number of junior roles a role may directly inherit.
+		#[pallet::constant]
+		type MaxJuniorsPerRole: Get<u32>;
+		/// Maximum number of roles in a separation of duty constraint.
+		#[pallet::constant]
+		type MaxRolesPerConstraint: Get<u32>;
+		/// Maximum number of delegations in a chain, the original delegation included.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
+		/// Maximum number of blocks a delegation may last.
+		#[pallet::constant]
+		type MaxDelegationPeriod: Get<BlockNumberFor<Self>>;
+		/// Maximum number of delegations an account may give, and of effective delegations it may
+		/// receive.
+		#[pallet::constant]
+		type MaxDelegationsPerAccount: Get<u32>;
+		/// Maximum length of the key of an account attribute.
+		#[pallet::constant]
+		type MaxAttributeKeyLength: Get<u32>;
+		/// Maximum length of the value of an account attribute.
+		#[pallet::constant]
+		type MaxAttributeValueLength: Get<u32>;
+		/// Maximum number of attributes of a single account.
+		#[pallet::constant]
+		type MaxAttributesPerAccount: Get<u32>;
+		/// Maximum number of operations of a condition.
+		#[pallet::constant]
+		type MaxConditionLength: Get<u32>;
+		/// Maximum number of snapshots of the policy kept at once.
+		#[pallet::constant]
+		type MaxSnapshots: Get<u32>;
+		/// Maximum number of operations of a policy batch.
+		#[pallet::constant]
+		type MaxPolicyBatchLength: Get<u32>;
+		/// The deposit held from the account creating a role.
+		#[pallet::constant]
+		type RoleDeposit: Get<BalanceOf<Self>>;
+		/// The deposit held from the account granting a permission or adding a deny rule.
+		#[pallet::constant]
+		type PermissionDeposit: Get<BalanceOf<Self>>;
+		/// The deposit held from the account attaching constraints or a condition to a
+		/// permission.
+		#[pallet::constant]
+		type ConstraintDeposit: Get<BalanceOf<Self>>;
+		/// Maximum number of roles a single account may own, and may be proposed as the owner of.
+		#[pallet::constant]
+		type MaxOwnedRoles: Get<u32>;
+		/// Number of blocks for which breaking the glass grants the break-glass role.
+		#[pallet::constant]
+		type BreakGlassPeriod: Get<BlockNumberFor<Self>>;
+		/// Maximum number of approvals a role may require for its assignments.
+		#[pallet::constant]
+		type MaxGrantApprovals: Get<u32>;
+		/// Number of blocks for which a grant proposal can be approved.
+		#[pallet::constant]
+		type GrantProposalLifetime: Get<BlockNumberFor<Self>>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds are held for roles, permissions, deny rules, constraints or conditions the
+		/// account created.
+		#[codec(index = 0)]
+		PolicyDeposit,
+	}
+
+	/// The id that will be given to the next created role.
+	#[pallet::storage]
+	#[pallet::getter(fn next_role_id)]
+	pub type NextRoleId<T> = StorageValue<_, RoleId, ValueQuery>;
+
+	/// The role registry.
+	#[pallet::storage]
+	#[pallet::getter(fn roles)]
+	pub type Roles<T: Config> = StorageMap<_, Twox64Concat, RoleId, RoleInfo<T>>;
+
+	/// The role whose holders administer every role without an admin role of its own.
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrap_admin)]
+	pub type BootstrapAdmin<T> = StorageValue<_, RoleId>;
+
+	/// Constraints on call arguments carried by the permissions granted to each role.
+	#[pallet::storage]
+	pub type PermissionConstraints<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoleId,
+		Blake2_128Concat,
+		Permission,
+		PermissionConstraintsOf<T>,
+	>;
+
+	/// Conditions on the context of calls carried by the permissions granted to each role.
+	#[pallet::storage]
+	pub type PermissionConditions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, Permission, Condition<T>>;
+
+	/// Attributes of each account, which conditions may refer to.
+	#[pallet::storage]
+	#[pallet::getter(fn attributes)]
+	pub type AccountAttributes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AccountAttributesOf<T>, ValueQuery>;
+
+	/// Deny rules of each role.
+	#[pallet::storage]
+	pub type RoleDenyRules<T> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, Permission, ()>;
+
+	/// Permissions granted to each role.
+	#[pallet::storage]
+	pub type RolePermissions<T> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, Permission, ()>;
+
+	/// Roles assigned to each account.
+	#[pallet::storage]
+	pub type AccountRoles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		RoleId,
+		RoleAssignment<BlockNumberFor<T>>,
+	>;
+
+	/// Holders of each role. Reverse index of [`AccountRoles`].
+	#[pallet::storage]
+	pub type RoleMembers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Junior roles directly inherited by each senior role.
+	#[pallet::storage]
+	pub type RoleJuniors<T> = StorageDoubleMap<_, Twox64Concat, RoleId, Twox64Concat, RoleId, ()>;
+
+	/// Senior roles directly inheriting each junior role. Reverse index of [`RoleJuniors`].
+	#[pallet::storage]
+	pub type RoleSeniors<T> = StorageDoubleMap<_, Twox64Concat, RoleId, Twox64Concat, RoleId, ()>;
+
+	/// Assignments expiring at each block, waiting to be removed by `on_idle`.
+	#[pallet::storage]
+	pub type RoleExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, RoleId),
+		(),
+	>;
+
+	/// The first block whose expiring assignments, delegations, usage windows and allowance windows
+	/// have not all been removed yet.
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Roles activated by each account. Only roles active in the session of an account are
+	/// considered by permission and admin checks.
+	#[pallet::storage]
+	pub type SessionRoles<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoleId, ()>;
+
+	/// The id that will be given to the next delegation.
+	#[pallet::storage]
+	pub type NextDelegationId<T> = StorageValue<_, DelegationId, ValueQuery>;
+
+	/// Delegations that have not been revoked nor removed after expiring.
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config> = StorageMap<_, Twox64Concat, DelegationId, Delegation<T>>;
+
+	/// Delegations received by each account.
+	#[pallet::storage]
+	pub type DelegationsTo<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, DelegationId, ()>;
+
+	/// Delegations given by each account.
+	#[pallet::storage]
+	pub type DelegationsBy<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, DelegationId, ()>;
+
+	/// Delegations of each role.
+	#[pallet::storage]
+	pub type RoleDelegations<T> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Twox64Concat, DelegationId, ()>;
+
+	/// Delegations indexed by the block they expire at.
+	#[pallet::storage]
+	pub type DelegationExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, DelegationId, ()>;
+
+	/// Calls of each account during the current window of each rate limited permission it used.
+	#[pallet::storage]
+	pub type UsageWindows<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Permission,
+		UsageWindow<BlockNumberFor<T>>,
+	>;
+
+	/// Usage windows indexed by the block they end at, waiting to be removed by `on_idle`.
+	#[pallet::storage]
+	pub type UsageWindowExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, Permission),
+		(),
+	>;
+
+	/// Allowance left to each account in the current period of each role it spent through.
+	#[pallet::storage]
+	pub type AllowanceWindows<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		RoleId,
+		AllowanceWindow<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// Allowance windows indexed by the block they refill at, waiting to be removed by `on_idle`.
+	#[pallet::storage]
+	pub type AllowanceRefills<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, RoleId),
+		(),
+	>;
+
+	/// The id that will be given to the next separation of duty constraint.
+	#[pallet::storage]
+	pub type NextConstraintId<T> = StorageValue<_, ConstraintId, ValueQuery>;
+
+	/// Static separation of duty constraints.
+	#[pallet::storage]
+	#[pallet::getter(fn sod_constraints)]
+	pub type SodConstraints<T: Config> =
+		StorageMap<_, Twox64Concat, ConstraintId, SodConstraint<T>>;
+
+	/// Separation of duty constraints covering each role.
+	#[pallet::storage]
+	pub type RoleConstraints<T> =
+		StorageDoubleMap<_, Twox64Concat, RoleId, Twox64Concat, ConstraintId, ()>;
+
+	/// Number of roles assigned to each account.
+	#[pallet::storage]
+	pub type AccountRoleCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The current version of the policy.
+	#[pallet::storage]
+	#[pallet::getter(fn policy_version)]
+	pub type CurrentPolicyVersion<T> = StorageValue<_, PolicyVersion, ValueQuery>;
+
+	/// Snapshots of the policy that may be rolled back to.
+	#[pallet::storage]
+	#[pallet::getter(fn snapshots)]
+	pub type Snapshots<T: Config> = StorageValue<_, SnapshotsOf<T>, ValueQuery>;
+
+	/// The parts of the policy each version changed, as they were before, in the order they
+	/// changed. Recorded while a snapshot may need them.
+	#[pallet::storage]
+	pub type PolicyJournal<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PolicyVersion, Twox64Concat, u32, PolicyChange<T>>;
+
+	/// Number of changes recorded in the journal of each version of the policy.
+	#[pallet::storage]
+	pub type PolicyJournalLength<T> = StorageMap<_, Twox64Concat, PolicyVersion, u32, ValueQuery>;
+
+	/// Every version up to this one has no journal left.
+	#[pallet::storage]
+	pub type JournalPrunedTo<T> = StorageValue<_, PolicyVersion, ValueQuery>;
+
+	/// The rollback in progress, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rollback)]
+	pub type PendingRollback<T> = StorageValue<_, Rollback>;
+
+	/// The id that will be given to the next created role template.
+	#[pallet::storage]
+	#[pallet::getter(fn next_template_id)]
+	pub type NextTemplateId<T> = StorageValue<_, TemplateId, ValueQuery>;
+
+	/// The role templates.
+	#[pallet::storage]
+	#[pallet::getter(fn templates)]
+	pub type Templates<T: Config> = StorageMap<_, Twox64Concat, TemplateId, RoleTemplate<T>>;
+
+	/// The deposits held for each role and for the parts of its policy, along with the account
+	/// they are held from.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_of)]
+	pub type Deposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoleId,
+		Twox64Concat,
+		DepositKind,
+		Deposit<T::AccountId, BalanceOf<T>>,
+	>;
+
+	/// The template tracked by each role created from one.
+	#[pallet::storage]
+	#[pallet::getter(fn template_of)]
+	pub type RoleTemplates<T> = StorageMap<_, Twox64Concat, RoleId, TemplateTracking>;
+
+	/// The owner of each role that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn owner_of)]
+	pub type RoleOwners<T: Config> = StorageMap<_, Twox64Concat, RoleId, T::AccountId>;
+
+	/// The roles owned by each account.
+	#[pallet::storage]
+	pub type OwnedRoles<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoleId, ()>;
+
+	/// Number of roles owned by each account.
+	#[pallet::storage]
+	pub type OwnedRoleCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The account proposed as the new owner of each role, until it accepts.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owner_of)]
+	pub type PendingOwners<T: Config> = StorageMap<_, Twox64Concat, RoleId, T::AccountId>;
+
+	/// The roles each account is proposed as the new owner of.
+	#[pallet::storage]
+	pub type PendingOwnedRoles<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoleId, ()>;
+
+	/// The block at which each frozen role was frozen.
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_since)]
+	pub type FrozenRoles<T: Config> = StorageMap<_, Twox64Concat, RoleId, BlockNumberFor<T>>;
+
+	#[pallet::type_value]
+	pub fn DefaultEnforcement<T: Config>() -> EnforcementMode {
+		T::DefaultEnforcementMode::get()
 	}
 
-	// The pallet's runtime storage items.
-	// https://docs.substrate.io/main-docs/build/runtime-storage/
+	/// How [`CheckAccess`] enforces access decisions.
 	#[pallet::storage]
-	#[pallet::getter(fn something)]
-	// Learn more about declaring storage items:
-	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	#[pallet::getter(fn enforcement_mode)]
+	pub type Enforcement<T: Config> =
+		StorageValue<_, EnforcementMode, ValueQuery, DefaultEnforcement<T>>;
+
+	/// The role granted to each account designated for break-glass access when it breaks the
+	/// glass.
+	#[pallet::storage]
+	#[pallet::getter(fn break_glass_role_of)]
+	pub type BreakGlassRoles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RoleId>;
+
+	/// The id of the next use of break-glass access.
+	#[pallet::storage]
+	pub type NextBreakGlassId<T> = StorageValue<_, BreakGlassId, ValueQuery>;
+
+	/// The uses of break-glass access awaiting review.
+	#[pallet::storage]
+	#[pallet::getter(fn break_glass_use)]
+	pub type BreakGlassUses<T: Config> =
+		StorageMap<_, Twox64Concat, BreakGlassId, BreakGlassUse<T::AccountId, BlockNumberFor<T>>>;
+
+	/// The use of break-glass access of each account awaiting review, which blocks any further
+	/// use by the account.
+	#[pallet::storage]
+	#[pallet::getter(fn unreviewed_break_glass_of)]
+	pub type UnreviewedBreakGlass<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BreakGlassId>;
+
+	/// The approvals required to assign each role that requires some.
+	#[pallet::storage]
+	#[pallet::getter(fn grant_approval_of)]
+	pub type GrantApprovals<T> = StorageMap<_, Twox64Concat, RoleId, GrantApproval>;
+
+	/// The id of the next grant proposal.
+	#[pallet::storage]
+	pub type NextGrantProposalId<T> = StorageValue<_, GrantProposalId, ValueQuery>;
+
+	/// Pending grant proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn grant_proposal)]
+	pub type GrantProposals<T: Config> =
+		StorageMap<_, Twox64Concat, GrantProposalId, GrantProposal<T>>;
+
+	/// Grant proposals indexed by the block they expire at.
+	#[pallet::storage]
+	pub type GrantProposalExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, GrantProposalId, ()>;
 
-	// Pallets use events to inform users when important changes are made.
-	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored { something: u32, who: T::AccountId },
+		/// A role was created. [role, who]
+		RoleCreated { role: RoleId, who: T::AccountId },
+		/// The name or metadata of a role was changed. [role, who]
+		RoleUpdated { role: RoleId, who: Option<T::AccountId> },
+		/// A role was removed from the registry. [role, who]
+		RoleDeleted { role: RoleId, who: Option<T::AccountId> },
+		/// A permission was granted to a role. [role, permission, who]
+		PermissionGranted { role: RoleId, permission: Permission, who: Option<T::AccountId> },
+		/// A permission was revoked from a role. [role, permission, who]
+		PermissionRevoked { role: RoleId, permission: Permission, who: Option<T::AccountId> },
+		/// A role was assigned to an account. [role, account, expires_at, who]
+		RoleAssigned {
+			role: RoleId,
+			account: T::AccountId,
+			expires_at: Option<BlockNumberFor<T>>,
+			who: Option<T::AccountId>,
+		},
+		/// A role was revoked from an account. [role, account, who]
+		RoleRevoked { role: RoleId, account: T::AccountId, who: Option<T::AccountId> },
+		/// An expired role assignment was removed. [role, account]
+		RoleExpired { role: RoleId, account: T::AccountId },
+		/// The admin role of a role was changed. [role, admin, who]
+		RoleAdminChanged { role: RoleId, admin: Option<RoleId>, who: Option<T::AccountId> },
+		/// The constraints carried by a permission of a role were replaced.
+		/// [role, permission, constraints, who]
+		PermissionConstraintsSet {
+			role: RoleId,
+			permission: Permission,
+			constraints: PermissionConstraintsOf<T>,
+			who: Option<T::AccountId>,
+		},
+		/// The rate limit of a role was changed. [role, limit, who]
+		RoleRateLimitChanged {
+			role: RoleId,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+			who: Option<T::AccountId>,
+		},
+		/// The spending allowance of a role was changed. [role, allowance, who]
+		RoleAllowanceChanged {
+			role: RoleId,
+			allowance: Option<Allowance<BalanceOf<T>, BlockNumberFor<T>>>,
+			who: Option<T::AccountId>,
+		},
+		/// An account spent part of the allowance of a role. [account, role, amount, remaining]
+		AllowanceSpent {
+			account: T::AccountId,
+			role: RoleId,
+			amount: BalanceOf<T>,
+			remaining: BalanceOf<T>,
+		},
+		/// The condition carried by a permission of a role was set or lifted.
+		/// [role, permission, condition, who]
+		PermissionConditionSet {
+			role: RoleId,
+			permission: Permission,
+			condition: Option<Condition<T>>,
+			who: Option<T::AccountId>,
+		},
+		/// An attribute of an account was set or removed. [account, key, value]
+		AttributeSet {
+			account: T::AccountId,
+			key: AttributeKey<T>,
+			value: Option<AttributeValue<T>>,
+		},
+		/// A deny rule was added to a role. [role, permission, who]
+		DenyRuleAdded { role: RoleId, permission: Permission, who: Option<T::AccountId> },
+		/// A deny rule was removed from a role. [role, permission, who]
+		DenyRuleRemoved { role: RoleId, permission: Permission, who: Option<T::AccountId> },
+		/// The maximum number of holders of a role was changed. [role, max_members, who]
+		RoleMaxMembersChanged { role: RoleId, max_members: Option<u32>, who: Option<T::AccountId> },
+		/// The prerequisite role of a role was changed. [role, prerequisite, who]
+		RolePrerequisiteChanged {
+			role: RoleId,
+			prerequisite: Option<RoleId>,
+			who: Option<T::AccountId>,
+		},
+		/// The bootstrap admin role was changed. [role]
+		BootstrapAdminChanged { role: Option<RoleId> },
+		/// A role was activated in the session of an account. [role, account]
+		RoleActivated { role: RoleId, account: T::AccountId },
+		/// A role was deactivated from the session of an account. [role, account]
+		RoleDeactivated { role: RoleId, account: T::AccountId },
+		/// A role was delegated. [delegation, role, delegator, delegate, expires_at]
+		RoleDelegated {
+			delegation: DelegationId,
+			role: RoleId,
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A delegation and every delegation derived from it were revoked. [delegation, who]
+		DelegationRevoked { delegation: DelegationId, who: Option<T::AccountId> },
+		/// A delegation expired and was removed. [delegation]
+		DelegationExpired { delegation: DelegationId },
+		/// A separation of duty constraint was registered. [constraint, roles, max_held, kind]
+		SodConstraintAdded {
+			constraint: ConstraintId,
+			roles: ConstraintRoles<T>,
+			max_held: u32,
+			kind: SodKind,
+		},
+		/// A separation of duty constraint was removed. [constraint]
+		SodConstraintRemoved { constraint: ConstraintId },
+		/// A senior role now inherits the permissions of a junior role. [senior, junior, who]
+		RolesLinked { senior: RoleId, junior: RoleId, who: Option<T::AccountId> },
+		/// A senior role no longer inherits the permissions of a junior role. [senior, junior,
+		/// who]
+		RolesUnlinked { senior: RoleId, junior: RoleId, who: Option<T::AccountId> },
+		/// A snapshot of the policy was taken. [version, name]
+		PolicySnapshotTaken { version: PolicyVersion, name: SnapshotName<T> },
+		/// A snapshot of the policy was removed. [version]
+		PolicySnapshotRemoved { version: PolicyVersion },
+		/// A batch of policy operations was applied. [batch, who]
+		PolicyBatchApplied { batch: PolicyBatch<T>, who: Option<T::AccountId> },
+		/// A rollback of the policy to a snapshot started. [from, to]
+		PolicyRollbackStarted { from: PolicyVersion, to: PolicyVersion },
+		/// The policy was rolled back to a snapshot. [from, to, changes, kept_roles]
+		PolicyRolledBack { from: PolicyVersion, to: PolicyVersion, changes: u32, kept_roles: u32 },
+		/// A role template was created. [template]
+		TemplateCreated { template: TemplateId },
+		/// A role template was replaced. [template, revision]
+		TemplateUpdated { template: TemplateId, revision: u32 },
+		/// A role template was removed. [template]
+		TemplateRemoved { template: TemplateId },
+		/// A role was created from a template. [role, template, tracked, who]
+		RoleInstantiated { role: RoleId, template: TemplateId, tracked: bool, who: T::AccountId },
+		/// A role was created as a copy of another. [role, source, who]
+		RoleCloned { role: RoleId, source: RoleId, who: Option<T::AccountId> },
+		/// The permissions and deny rules of a role were set to those of the template it tracks.
+		/// [role, template, revision, who]
+		RoleSynced { role: RoleId, template: TemplateId, revision: u32, who: Option<T::AccountId> },
+		/// A role stopped tracking its template. [role, template, who]
+		RoleUntracked { role: RoleId, template: TemplateId, who: Option<T::AccountId> },
+		/// A new owner of a role was proposed, or the proposal withdrawn. [role, owner, who]
+		RoleOwnerProposed { role: RoleId, owner: Option<T::AccountId>, who: Option<T::AccountId> },
+		/// The owner of a role changed. [role, owner]
+		RoleOwnerChanged { role: RoleId, owner: Option<T::AccountId> },
+		/// The owner of a role was reaped, leaving the role to its admin role or to
+		/// `AdminOrigin`. [role, owner]
+		RoleOrphaned { role: RoleId, owner: T::AccountId },
+		/// A role was frozen. [role]
+		RoleFrozen { role: RoleId },
+		/// A role was thawed. [role]
+		RoleThawed { role: RoleId },
+		/// The enforcement mode of access decisions was set. [mode]
+		EnforcementModeSet { mode: EnforcementMode },
+		/// A transaction whose signer may not dispatch its call was let through, as access
+		/// decisions are only audited. [who, permission, error]
+		AccessDenialAudited {
+			who: T::AccountId,
+			permission: Option<Permission>,
+			error: DispatchError,
+		},
+		/// An account was designated for break-glass access to a role, or no longer is.
+		/// [account, role]
+		BreakGlassRoleSet { account: T::AccountId, role: Option<RoleId> },
+		/// EMERGENCY ACCESS: an account broke the glass and holds the break-glass role until
+		/// `expires_at`, pending review. [id, account, role, expires_at]
+		BreakGlassActivated {
+			id: BreakGlassId,
+			account: T::AccountId,
+			role: RoleId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A use of break-glass access was reviewed. [id, account, reviewer]
+		BreakGlassReviewed {
+			id: BreakGlassId,
+			account: T::AccountId,
+			reviewer: Option<T::AccountId>,
+		},
+		/// The approvals required to assign a role were set. [role, approval]
+		GrantApprovalSet { role: RoleId, approval: Option<GrantApproval> },
+		/// The assignment of a role requiring approvals was proposed. [id, account, role,
+		/// proposer]
+		GrantProposed {
+			id: GrantProposalId,
+			account: T::AccountId,
+			role: RoleId,
+			proposer: T::AccountId,
+		},
+		/// A grant proposal was approved. [id, approver]
+		GrantApproved { id: GrantProposalId, approver: T::AccountId },
+		/// A grant proposal reached its threshold of approvals and its role was assigned.
+		/// [id, account, role, expires_at]
+		GrantExecuted {
+			id: GrantProposalId,
+			account: T::AccountId,
+			role: RoleId,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// A grant proposal was rejected by an approver. [id, who]
+		GrantRejected { id: GrantProposalId, who: T::AccountId },
+		/// A grant proposal was cancelled. [id, who]
+		GrantCancelled { id: GrantProposalId, who: Option<T::AccountId> },
+		/// A grant proposal expired before reaching its threshold. [id]
+		GrantProposalExpired { id: GrantProposalId },
+		/// A deposit was held for part of the policy of a role. [role, kind, depositor, amount]
+		DepositHeld {
+			role: RoleId,
+			kind: DepositKind,
+			depositor: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A deposit held for part of the policy of a role was released. [role, kind, depositor,
+		/// amount]
+		DepositReleased {
+			role: RoleId,
+			kind: DepositKind,
+			depositor: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
-	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Error names should be descriptive.
-		NoneValue,
-		/// Errors should have helpful documentation associated with them.
-		StorageOverflow,
+		/// The role does not exist.
+		RoleNotFound,
+		/// No more role ids are available.
+		RoleIdOverflow,
+		/// The role already has this permission.
+		PermissionAlreadyGranted,
+		/// The role does not have this permission.
+		PermissionNotGranted,
+		/// The role already has the maximum number of permissions and deny rules.
+		TooManyPermissions,
+		/// The account already holds the role.
+		RoleAlreadyAssigned,
+		/// The account does not hold the role.
+		RoleNotAssigned,
+		/// The account already holds the maximum number of roles.
+		TooManyRoles,
+		/// The caller does not hold the admin role of the role.
+		NotRoleAdmin,
+		/// The role already carries this deny rule.
+		DenyRuleAlreadyAdded,
+		/// The role does not carry this deny rule.
+		DenyRuleNotFound,
+		/// The role is assigned, or delegated as a whole, to as many accounts as it may be.
+		TooManyMembers,
+		/// The account does not hold the prerequisite role of the role, or holds it for less long.
+		PrerequisiteNotHeld,
+		/// The prerequisite would be the role itself or start a chain of prerequisites no account
+		/// could hold.
+		InvalidPrerequisite,
+		/// The account holds a role requiring the role to revoke.
+		RoleIsPrerequisite,
+		/// The account is not permitted to dispatch the call.
+		AccessDenied,
+		/// The account already dispatched as many calls using the permission as its rate limit
+		/// allows in the current window.
+		RateLimitExceeded,
+		/// The rate limit allows no call or has an empty window.
+		InvalidRateLimit,
+		/// The call spends more than the allowance left to the account in the current period.
+		AllowanceExceeded,
+		/// The allowance has an empty period.
+		InvalidAllowance,
+		/// The condition does not evaluate to a single value.
+		InvalidCondition,
+		/// The account has as many attributes as it may have.
+		TooManyAttributes,
+		/// The role is already active in the session.
+		RoleAlreadyActive,
+		/// The role is not active in the session.
+		RoleNotActive,
+		/// The delegation does not exist or is no longer effective.
+		DelegationNotFound,
+		/// The origin is not the delegate of the parent delegation, or delegates another role.
+		NotDelegate,
+		/// The delegation chain would be longer than `MaxDelegationDepth`.
+		DelegationTooDeep,
+		/// The delegated permissions are not all available to the delegator.
+		PermissionsNotDelegable,
+		/// The account gives or receives too many delegations.
+		TooManyDelegations,
+		/// No more delegation ids are available.
+		DelegationIdOverflow,
+		/// An account cannot delegate to itself.
+		SelfDelegation,
+		/// The change would authorize an account for more roles of a separation of duty constraint
+		/// than allowed.
+		SeparationOfDutyViolated,
+		/// The constraint must cover at least two distinct roles and allow fewer of them than it
+		/// covers.
+		InvalidConstraint,
+		/// The separation of duty constraint does not exist.
+		ConstraintNotFound,
+		/// No more constraint ids are available.
+		ConstraintIdOverflow,
+		/// More role holders and delegations must be checked than stated by the `holders` witness.
+		HoldersWitnessTooLow,
+		/// The expiry of an assignment or a delegation must be in the future, and a delegation
+		/// must not outlast `MaxDelegationPeriod` nor what it was derived from.
+		InvalidExpiry,
+		/// The role is still assigned to an account, linked to another role or covered by a
+		/// constraint.
+		RoleInUse,
+		/// The senior role already inherits the junior role directly.
+		LinkAlreadyExists,
+		/// The senior role does not inherit the junior role directly.
+		LinkNotFound,
+		/// The link would make a role inherit itself.
+		HierarchyCycle,
+		/// The link would create a chain of roles longer than `MaxHierarchyDepth`.
+		HierarchyTooDeep,
+		/// The senior role already inherits `MaxJuniorsPerRole` junior roles.
+		TooManyJuniors,
+		/// There is no snapshot of this version of the policy.
+		SnapshotNotFound,
+		/// A snapshot of the current version of the policy was already taken.
+		SnapshotAlreadyTaken,
+		/// As many snapshots of the policy are kept as may be.
+		TooManySnapshots,
+		/// The policy may not change while it is being rolled back.
+		RollbackInProgress,
+		/// The change makes more changes to the policy than can be recorded.
+		TooManyPolicyChanges,
+		/// The policy batch has no operation.
+		EmptyPolicyBatch,
+		/// The role template does not exist.
+		TemplateNotFound,
+		/// No more template ids are available.
+		TemplateIdOverflow,
+		/// The template lists a permission or a deny rule twice.
+		InvalidTemplate,
+		/// The template is still tracked by a role.
+		TemplateInUse,
+		/// The role does not track a template.
+		RoleNotTracking,
+		/// The caller does not own the role.
+		NotRoleOwner,
+		/// The caller was not proposed as the new owner of the role.
+		NotPendingOwner,
+		/// The account already owns, or is proposed as the owner of, the maximum number of roles.
+		TooManyOwnedRoles,
+		/// The role is already frozen.
+		AlreadyFrozen,
+		/// The role is not frozen.
+		NotFrozen,
+		/// The role is frozen, so it cannot be copied until it is thawed.
+		RoleIsFrozen,
+		/// The account is not designated for break-glass access.
+		NotBreakGlassAccount,
+		/// The previous use of break-glass access of the account has not been reviewed yet.
+		BreakGlassUnreviewed,
+		/// No use of break-glass access awaiting review has the given id.
+		BreakGlassUseNotFound,
+		/// Break-glass access must be reviewed by another account than the one using it.
+		BreakGlassSelfReview,
+		/// The next break-glass id would overflow.
+		BreakGlassIdOverflow,
+		/// The role requires approvals, so it can only be passed on through a grant proposal.
+		ApprovalRequired,
+		/// The role does not require approvals.
+		NoApprovalRequired,
+		/// The threshold of approvals must be between one and `MaxGrantApprovals`.
+		InvalidThreshold,
+		/// No pending grant proposal has the given id.
+		GrantProposalNotFound,
+		/// The grant proposal expired.
+		ProposalExpired,
+		/// The caller does not hold the approver role of the proposed role.
+		NotApprover,
+		/// The caller already approved the grant proposal.
+		AlreadyApproved,
+		/// The proposer of a grant and the account it is proposed to cannot approve it.
+		ApproverNotIndependent,
+		/// The role is inherited by a senior role, whose holders would not need approvals.
+		RoleHasSeniors,
+		/// The grant proposal has the maximum number of approvals.
+		TooManyApprovals,
+		/// The caller did not make the grant proposal.
+		NotProposer,
+		/// The next grant proposal id would overflow.
+		GrantProposalIdOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used = Self::remove_expired(now, remaining_weight);
+			used.saturating_add(Self::maintain_journal(remaining_weight.saturating_sub(used)))
+		}
 	}
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
+		/// Create a new role with the given name and metadata.
+		///
+		/// The role is given the next available [`RoleId`] and is owned by the caller, from whom
+		/// `RoleDeposit` is held.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
-			// https://docs.substrate.io/main-docs/build/origins/
+		#[pallet::weight(T::WeightInfo::create_role())]
+		pub fn create_role(
+			origin: OriginFor<T>,
+			name: RoleName<T>,
+			metadata: RoleMetadata<T>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::new_policy_version()?;
+			let role = Self::do_create_role(RoleInfo::new(name, metadata))?;
+			Self::hold_deposit(Some(&who), role, DepositKind::Role, T::RoleDeposit::get())?;
+			Self::set_owner(role, Some(&who))?;
 
-			// Update storage.
-			<Something<T>>::put(something);
-
-			// Emit an event.
-			Self::deposit_event(Event::SomethingStored { something, who });
-			// Return a successful DispatchResultWithPostInfo
+			Self::deposit_event(Event::RoleCreated { role, who });
 			Ok(())
 		}
 
-		/// An example dispatchable that may throw a custom error.
+		/// Replace the name and metadata of an existing role.
+		///
+		/// The origin must be `AdminOrigin`, the owner of the role or a holder of the role's admin
+		/// role.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::cause_error())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-
-			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
+		#[pallet::weight(T::WeightInfo::update_role())]
+		pub fn update_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			name: RoleName<T>,
+			metadata: RoleMetadata<T>,
+		) -> DispatchResult {
+			let who = Self::ensure_owner_or_role_admin(origin, role)?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+				info.name = name;
+				info.metadata = metadata;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoleUpdated { role, who });
+			Ok(())
+		}
+
+		/// Remove a role from the registry together with all of its permissions.
+		///
+		/// The role must not be assigned to any account, linked to any other role nor covered by a
+		/// constraint. Every deposit held for it is released. The origin must be `AdminOrigin`, the
+		/// owner of the role or a holder of the role's admin role.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::delete_role(T::MaxPermissionsPerRole::get()))]
+		pub fn delete_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_owner_or_role_admin(origin, role)?;
+			Self::new_policy_version()?;
+
+			ensure!(
+				RoleMembers::<T>::iter_key_prefix(role).next().is_none() &&
+					RoleJuniors::<T>::iter_key_prefix(role).next().is_none() &&
+					RoleSeniors::<T>::iter_key_prefix(role).next().is_none() &&
+					RoleConstraints::<T>::iter_key_prefix(role).next().is_none(),
+				Error::<T>::RoleInUse
+			);
+			if Self::journaling() {
+				Self::journal_role(role)?;
+				for permission in RolePermissions::<T>::iter_key_prefix(role) {
+					Self::journal_permission(role, permission)?;
+				}
+				for permission in RoleDenyRules::<T>::iter_key_prefix(role) {
+					Self::journal_deny_rule(role, permission)?;
+				}
+				if GrantApprovals::<T>::contains_key(role) {
+					Self::journal_grant_approval(role)?;
+				}
+			}
+			let info = Roles::<T>::take(role).ok_or(Error::<T>::RoleNotFound)?;
+			let _ = RolePermissions::<T>::clear_prefix(role, info.permissions, None);
+			let _ = RoleDenyRules::<T>::clear_prefix(role, info.deny_rules, None);
+			let _ = PermissionConstraints::<T>::clear_prefix(role, info.permissions, None);
+			let _ = PermissionConditions::<T>::clear_prefix(role, info.permissions, None);
+			Self::stop_tracking(role);
+			Self::release_deposits(role);
+			Self::set_owner(role, None)?;
+			Self::set_pending_owner(role, None)?;
+			FrozenRoles::<T>::remove(role);
+			GrantApprovals::<T>::remove(role);
+
+			Self::deposit_event(Event::RoleDeleted { role, who });
+			Ok(Some(T::WeightInfo::delete_role(info.permissions + info.deny_rules)).into())
+		}
+
+		/// Grant `permission` to `role`.
+		///
+		/// `permission` may be a wildcard covering every call of a pallet or of the runtime. The
+		/// origin must be `AdminOrigin` or a holder of the role's admin role, from whom
+		/// `PermissionDeposit` is then held.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::grant_permission())]
+		pub fn grant_permission(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::do_grant_permission(role, permission, who.as_ref())?;
+
+			Self::deposit_event(Event::PermissionGranted { role, permission, who });
+			Ok(())
+		}
+
+		/// Revoke `permission` from `role`, along with its constraints and condition.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::revoke_permission())]
+		pub fn revoke_permission(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::do_revoke_permission(role, permission)?;
+
+			Self::deposit_event(Event::PermissionRevoked { role, permission, who });
+			Ok(())
+		}
+
+		/// Assign `role` to `account`, optionally until the block `expires_at`.
+		///
+		/// An expired assignment of the same role is replaced. The origin must be `AdminOrigin` or
+		/// a holder of the role's admin role.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::assign_role())]
+		pub fn assign_role(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			#[pallet::compact] role: RoleId,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::ensure_no_approval_required(who.as_ref(), role)?;
+			let account = T::Lookup::lookup(account)?;
+			Self::do_assign_role(&account, role, expires_at)?;
+
+			Self::deposit_event(Event::RoleAssigned { role, account, expires_at, who });
+			Ok(())
+		}
+
+		/// Revoke `role` from `account`.
+		///
+		/// Fails while `account` holds a role that requires `role`. The origin must be
+		/// `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			let account = T::Lookup::lookup(account)?;
+			Self::do_unassign_role(&account, role)?;
+
+			Self::deposit_event(Event::RoleRevoked { role, account, who });
+			Ok(())
+		}
+
+		/// Make `senior` inherit every permission of `junior`.
+		///
+		/// Fails if `junior` already inherits `senior`, directly or indirectly, if the link would
+		/// create a chain of roles longer than `MaxHierarchyDepth`, or if it would authorize a
+		/// holder or delegate of `senior` or of a role inheriting it for conflicting roles.
+		/// `holders` is an upper bound on the number of such assignments and delegations. The
+		/// origin must be `AdminOrigin` or hold the admin roles of both `senior` and `junior`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::link_roles(T::MaxHierarchyDepth::get(), *holders))]
+		pub fn link_roles(
+			origin: OriginFor<T>,
+			#[pallet::compact] senior: RoleId,
+			#[pallet::compact] junior: RoleId,
+			#[pallet::compact] holders: u32,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[senior, junior])?;
+			Self::ensure_no_approval_required(who.as_ref(), junior)?;
+			Self::new_policy_version()?;
+			Self::do_link_roles(senior, junior, holders)?;
+
+			Self::deposit_event(Event::RolesLinked { senior, junior, who });
+			Ok(())
+		}
+
+		/// Make `senior` stop inheriting the permissions of `junior`.
+		///
+		/// The origin must be `AdminOrigin` or hold the admin roles of both `senior` and `junior`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::unlink_roles())]
+		pub fn unlink_roles(
+			origin: OriginFor<T>,
+			#[pallet::compact] senior: RoleId,
+			#[pallet::compact] junior: RoleId,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[senior, junior])?;
+			Self::new_policy_version()?;
+			Self::do_unlink_roles(senior, junior)?;
+
+			Self::deposit_event(Event::RolesUnlinked { senior, junior, who });
+			Ok(())
+		}
+
+		/// Set the role whose holders administer `role`.
+		///
+		/// With `None`, `role` is administered by the bootstrap admin role. The origin must be
+		/// `AdminOrigin` or a holder of the current admin role of `role`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_role_admin())]
+		pub fn set_role_admin(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			admin: Option<RoleId>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			if let Some(admin) = admin {
+				ensure!(Roles::<T>::contains_key(admin), Error::<T>::RoleNotFound);
+			}
+			Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+				info.admin = admin;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoleAdminChanged { role, admin, who });
+			Ok(())
+		}
+
+		/// Set the role whose holders administer every role without an admin role of its own.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_bootstrap_admin())]
+		pub fn set_bootstrap_admin(origin: OriginFor<T>, role: Option<RoleId>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::new_policy_version()?;
+			Self::journal(|| PolicyChange::BootstrapAdmin(BootstrapAdmin::<T>::get()))?;
+
+			if let Some(role) = role {
+				ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			}
+			BootstrapAdmin::<T>::set(role);
+
+			Self::deposit_event(Event::BootstrapAdminChanged { role });
+			Ok(())
+		}
+
+		/// Register a separation of duty constraint: no account may hold, or have active in its
+		/// session if `kind` is [`SodKind::Dynamic`], more than `max_held` of `roles`.
+		///
+		/// Fails if an existing assignment, delegation or session already violates the constraint.
+		/// `holders` is an upper bound on the number of assignments and delegations of one of
+		/// `roles` or a role inheriting them. The origin must be `AdminOrigin`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::add_sod_constraint(roles.len() as u32, *holders))]
+		pub fn add_sod_constraint(
+			origin: OriginFor<T>,
+			roles: ConstraintRoles<T>,
+			max_held: u32,
+			kind: SodKind,
+			#[pallet::compact] holders: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut distinct = roles.clone().into_inner();
+			distinct.sort();
+			distinct.dedup();
+			ensure!(
+				distinct.len() == roles.len() && max_held > 0 && (max_held as usize) < roles.len(),
+				Error::<T>::InvalidConstraint
+			);
+			for role in roles.iter() {
+				ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			}
+
+			let constraint = NextConstraintId::<T>::get();
+			let next = constraint.checked_add(1).ok_or(Error::<T>::ConstraintIdOverflow)?;
+			SodConstraints::<T>::insert(
+				constraint,
+				SodConstraint { roles: roles.clone(), max_held, kind },
+			);
+			for role in roles.iter() {
+				RoleConstraints::<T>::insert(role, constraint, ());
+			}
+			NextConstraintId::<T>::put(next);
+
+			let affected = roles.iter().flat_map(|role| Self::inheriting_roles(*role)).collect();
+			Self::ensure_holders_respect_constraints(affected, holders)?;
+
+			Self::deposit_event(Event::SodConstraintAdded { constraint, roles, max_held, kind });
+			Ok(())
+		}
+
+		/// Remove a static separation of duty constraint.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_sod_constraint())]
+		pub fn remove_sod_constraint(
+			origin: OriginFor<T>,
+			#[pallet::compact] constraint: ConstraintId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let removed =
+				SodConstraints::<T>::take(constraint).ok_or(Error::<T>::ConstraintNotFound)?;
+			for role in removed.roles.iter() {
+				RoleConstraints::<T>::remove(role, constraint);
+			}
+
+			Self::deposit_event(Event::SodConstraintRemoved { constraint });
+			Ok(())
+		}
+
+		/// Activate `role`, held by the origin, in its session.
+		///
+		/// Fails if the roles active in the session, along with the roles delegated to the origin,
+		/// would violate a separation of duty constraint.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::activate_role())]
+		pub fn activate_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::has_role(&who, role), Error::<T>::RoleNotAssigned);
+			ensure!(!SessionRoles::<T>::contains_key(&who, role), Error::<T>::RoleAlreadyActive);
+
+			let mut authorized = Self::session_authorized_roles(&who);
+			authorized.extend(Self::delegated_roles(&who));
+			authorized.extend(Self::inherited_roles(role));
+			Self::ensure_separation_of_duty(&authorized, true)?;
+			SessionRoles::<T>::insert(&who, role, ());
+
+			Self::deposit_event(Event::RoleActivated { role, account: who });
+			Ok(())
+		}
+
+		/// Deactivate `role` from the session of the origin.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::deactivate_role())]
+		pub fn deactivate_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(SessionRoles::<T>::contains_key(&who, role), Error::<T>::RoleNotActive);
+			SessionRoles::<T>::remove(&who, role);
+
+			Self::deposit_event(Event::RoleDeactivated { role, account: who });
+			Ok(())
+		}
+
+		/// Delegate `role`, or only `permissions` of it, to `delegate` until `expires_at`.
+		///
+		/// Delegated permissions may be wildcards, as long as the role covers them.
+		///
+		/// Without a `parent`, the origin must hold `role`. Otherwise the origin must be the
+		/// delegate of the effective delegation `parent` of `role`, and may only delegate
+		/// permissions delegated to it. The delegation must not outlast `MaxDelegationPeriod`
+		/// nor the assignment or delegation it is derived from. `delegate` must be another account,
+		/// and the roles whose permissions are delegated must not violate a separation of duty
+		/// constraint for it: delegated roles are in effect without being activated, so they are
+		/// checked against dynamic constraints along with the roles active in its session.
+		///
+		/// Delegating the whole role makes `delegate` a holder of it: `delegate` must hold the
+		/// prerequisite of `role` until `expires_at`, and counts against its `max_members` along
+		/// with its assignments and the other accounts it is delegated to as a whole.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::delegate_role(T::MaxDelegationDepth::get()))]
+		pub fn delegate_role(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			#[pallet::compact] role: RoleId,
+			permissions: Option<DelegatedPermissions<T>>,
+			expires_at: BlockNumberFor<T>,
+			parent: Option<DelegationId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_no_approval_required(Some(&who), role)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(delegate != who, Error::<T>::SelfDelegation);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				expires_at > now && expires_at <= now.saturating_add(T::MaxDelegationPeriod::get()),
+				Error::<T>::InvalidExpiry
+			);
+
+			let (depth, until, available) = match parent {
+				None => {
+					let assignment = AccountRoles::<T>::get(&who, role)
+						.filter(|assignment| assignment.is_active(&now))
+						.ok_or(Error::<T>::RoleNotAssigned)?;
+					(1, assignment.expires_at, None)
+				},
+				Some(parent) => {
+					ensure!(
+						Self::delegation_chain(parent).is_some(),
+						Error::<T>::DelegationNotFound
+					);
+					let parent =
+						Delegations::<T>::get(parent).ok_or(Error::<T>::DelegationNotFound)?;
+					ensure!(parent.delegate == who && parent.role == role, Error::<T>::NotDelegate);
+					ensure!(
+						parent.depth < T::MaxDelegationDepth::get(),
+						Error::<T>::DelegationTooDeep
+					);
+					(parent.depth + 1, Some(parent.expires_at), parent.permissions)
+				},
+			};
+			ensure!(until.map_or(true, |until| expires_at <= until), Error::<T>::InvalidExpiry);
+			match (&permissions, &available) {
+				(Some(permissions), Some(available)) => ensure!(
+					permissions.iter().all(|p| available.iter().any(|a| a.covers(p))),
+					Error::<T>::PermissionsNotDelegable
+				),
+				(Some(permissions), None) => ensure!(
+					permissions.iter().all(|p| Self::role_grants(role, *p)),
+					Error::<T>::PermissionsNotDelegable
+				),
+				(None, Some(_)) => return Err(Error::<T>::PermissionsNotDelegable.into()),
+				(None, None) => Self::ensure_can_hold_delegated(&delegate, role, expires_at)?,
+			}
+			let mut delegated = Self::delegated_roles(&delegate);
+			delegated.extend(Self::roles_delegated_by(role, permissions.as_ref()));
+			Self::ensure_account_separation_of_duty(&delegate, delegated)?;
+			// Delegations that are no longer effective only wait to expire, and leave room for
+			// new ones.
+			let max = T::MaxDelegationsPerAccount::get() as usize;
+			ensure!(
+				DelegationsBy::<T>::iter_key_prefix(&who).count() < max &&
+					DelegationsTo::<T>::iter_key_prefix(&delegate)
+						.filter(|delegation| Self::delegation_chain(*delegation).is_some())
+						.count() <
+						max,
+				Error::<T>::TooManyDelegations
+			);
+
+			let delegation = NextDelegationId::<T>::get();
+			let next = delegation.checked_add(1).ok_or(Error::<T>::DelegationIdOverflow)?;
+			Delegations::<T>::insert(
+				delegation,
+				Delegation {
+					delegator: who.clone(),
+					delegate: delegate.clone(),
+					role,
+					permissions,
+					parent,
+					depth,
+					expires_at,
+				},
+			);
+			DelegationsBy::<T>::insert(&who, delegation, ());
+			DelegationsTo::<T>::insert(&delegate, delegation, ());
+			RoleDelegations::<T>::insert(role, delegation, ());
+			DelegationExpiries::<T>::insert(expires_at, delegation, ());
+			NextDelegationId::<T>::put(next);
+
+			Self::deposit_event(Event::RoleDelegated {
+				delegation,
+				role,
+				delegator: who,
+				delegate,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Revoke `delegation`, and with it every delegation derived from it.
+		///
+		/// The origin must be the delegator, the delegate declining the delegation, `AdminOrigin`
+		/// or a holder of the role's admin role.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::revoke_delegation())]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			#[pallet::compact] delegation: DelegationId,
+		) -> DispatchResult {
+			let info = Delegations::<T>::get(delegation).ok_or(Error::<T>::DelegationNotFound)?;
+			let who = match ensure_signed(origin.clone()) {
+				Ok(who) if who == info.delegator || who == info.delegate => Some(who),
+				_ => Self::ensure_role_admin(origin, &[info.role])?,
+			};
+			Self::do_remove_delegation(delegation);
+
+			Self::deposit_event(Event::DelegationRevoked { delegation, who });
+			Ok(())
+		}
+
+		/// Limit the number of accounts `role` may be assigned to, or lift the limit.
+		///
+		/// Fails if `role` is already assigned to more accounts. The origin must be `AdminOrigin`
+		/// or a holder of the role's admin role.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_max_members())]
+		pub fn set_max_members(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			max_members: Option<u32>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+				ensure!(
+					max_members.map_or(true, |max| info.members <= max),
+					Error::<T>::TooManyMembers
+				);
+				info.max_members = max_members;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoleMaxMembersChanged { role, max_members, who });
+			Ok(())
+		}
+
+		/// Require accounts to hold `prerequisite` for `role` to be assigned to them, or lift the
+		/// requirement.
+		///
+		/// Fails if a holder of `role` does not hold `prerequisite` for at least as long. `members`
+		/// is an upper bound on the number of holders of `role`. The origin must be `AdminOrigin`
+		/// or a holder of the role's admin role.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_prerequisite(*members))]
+		pub fn set_prerequisite(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			prerequisite: Option<RoleId>,
+			#[pallet::compact] members: u32,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			let mut info = Roles::<T>::get(role).ok_or(Error::<T>::RoleNotFound)?;
+			if let Some(prerequisite) = prerequisite {
+				Self::ensure_valid_prerequisite(role, prerequisite)?;
+				ensure!(info.members <= members, Error::<T>::HoldersWitnessTooLow);
+				for (account, assignment) in
+					RoleMembers::<T>::iter_key_prefix(role).filter_map(|account| {
+						AccountRoles::<T>::get(&account, role).map(|a| (account, a))
+					}) {
+					Self::ensure_prerequisite_held(&account, prerequisite, assignment.expires_at)?;
+				}
+			}
+			info.prerequisite = prerequisite;
+			Roles::<T>::insert(role, info);
+
+			Self::deposit_event(Event::RolePrerequisiteChanged { role, prerequisite, who });
+			Ok(())
+		}
+
+		/// Deny `permission` to `role`, to every role inheriting it and to every account with
+		/// `role` in scope, whatever else allows it.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the role's admin role, from whom
+		/// `PermissionDeposit` is then held.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::add_deny_rule())]
+		pub fn add_deny_rule(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::do_add_deny_rule(role, permission, who.as_ref())?;
+
+			Self::deposit_event(Event::DenyRuleAdded { role, permission, who });
+			Ok(())
+		}
+
+		/// Remove the deny rule `permission` from `role`.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::remove_deny_rule())]
+		pub fn remove_deny_rule(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::do_remove_deny_rule(role, permission)?;
+
+			Self::deposit_event(Event::DenyRuleRemoved { role, permission, who });
+			Ok(())
+		}
+
+		/// Replace the constraints on call arguments carried by `permission` of `role`.
+		///
+		/// `permission` then only allows calls satisfying every constraint. Empty `constraints`
+		/// lift every constraint. The origin must be `AdminOrigin` or a holder of the role's admin
+		/// role, from whom `ConstraintDeposit` is then held unless the permission already carries
+		/// constraints.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_permission_constraints())]
+		pub fn set_permission_constraints(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+			constraints: PermissionConstraintsOf<T>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_permission(role, permission)?;
+
+			ensure!(
+				RolePermissions::<T>::contains_key(role, permission),
+				Error::<T>::PermissionNotGranted
+			);
+			let kind = DepositKind::Constraints(permission);
+			if constraints.is_empty() {
+				PermissionConstraints::<T>::remove(role, permission);
+				Self::release_deposit(role, kind);
+			} else {
+				PermissionConstraints::<T>::insert(role, permission, &constraints);
+				Self::hold_deposit(who.as_ref(), role, kind, T::ConstraintDeposit::get())?;
+			}
+
+			Self::deposit_event(Event::PermissionConstraintsSet {
+				role,
+				permission,
+				constraints,
+				who,
+			});
+			Ok(())
+		}
+
+		/// Limit how many calls each account may dispatch using each permission of `role` within
+		/// a window of blocks, or lift the limit.
+		///
+		/// Calls already counted in current windows count against the new limit. The origin must
+		/// be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			ensure!(
+				limit.map_or(true, |limit| limit.calls > 0 && !limit.period.is_zero()),
+				Error::<T>::InvalidRateLimit
+			);
+			Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+				info.rate_limit = limit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoleRateLimitChanged { role, limit, who });
+			Ok(())
+		}
+
+		/// Let each holder of `role` spend `allowance` per period through the permissions of the
+		/// role, or lift the limit.
+		///
+		/// Periods already started keep the allowance left in them until they end. The origin must
+		/// be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_allowance())]
+		pub fn set_allowance(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			allowance: Option<Allowance<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			ensure!(
+				allowance.map_or(true, |allowance| !allowance.period.is_zero()),
+				Error::<T>::InvalidAllowance
+			);
+			Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+				info.allowance = allowance;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoleAllowanceChanged { role, allowance, who });
+			Ok(())
+		}
+
+		/// Set the attribute `key` of `account` to `value`, or remove it.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			key: AttributeKey<T>,
+			value: Option<AttributeValue<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let account = T::Lookup::lookup(account)?;
+
+			AccountAttributes::<T>::try_mutate(&account, |attributes| {
+				attributes.retain(|(k, _)| *k != key);
+				match &value {
+					Some(value) => attributes
+						.try_push((key.clone(), value.clone()))
+						.map_err(|_| Error::<T>::TooManyAttributes),
+					None => Ok(()),
+				}
+			})?;
+
+			Self::deposit_event(Event::AttributeSet { account, key, value });
+			Ok(())
+		}
+
+		/// Make `permission` of `role` only allow calls while `condition` holds, or lift the
+		/// condition.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the role's admin role, from whom
+		/// `ConstraintDeposit` is then held unless the permission already carries a condition.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_permission_condition())]
+		pub fn set_permission_condition(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			permission: Permission,
+			condition: Option<Condition<T>>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_permission(role, permission)?;
+
+			ensure!(
+				RolePermissions::<T>::contains_key(role, permission),
+				Error::<T>::PermissionNotGranted
+			);
+			let kind = DepositKind::Condition(permission);
+			match &condition {
+				Some(condition) => {
+					ensure!(condition.is_well_formed(), Error::<T>::InvalidCondition);
+					PermissionConditions::<T>::insert(role, permission, condition);
+					Self::hold_deposit(who.as_ref(), role, kind, T::ConstraintDeposit::get())?;
+				},
+				None => {
+					PermissionConditions::<T>::remove(role, permission);
+					Self::release_deposit(role, kind);
 				},
 			}
+
+			Self::deposit_event(Event::PermissionConditionSet { role, permission, condition, who });
+			Ok(())
+		}
+
+		/// Take a snapshot of the current version of the policy, which root may roll back to.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::take_snapshot())]
+		pub fn take_snapshot(origin: OriginFor<T>, name: SnapshotName<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(!PendingRollback::<T>::exists(), Error::<T>::RollbackInProgress);
+			let version = CurrentPolicyVersion::<T>::get();
+			Snapshots::<T>::try_mutate(|snapshots| -> DispatchResult {
+				ensure!(
+					snapshots.last().map_or(true, |(last, _)| *last < version),
+					Error::<T>::SnapshotAlreadyTaken
+				);
+				snapshots
+					.try_push((version, name.clone()))
+					.map_err(|_| Error::<T>::TooManySnapshots.into())
+			})?;
+
+			Self::deposit_event(Event::PolicySnapshotTaken { version, name });
+			Ok(())
+		}
+
+		/// Remove the snapshot of `version` of the policy.
+		///
+		/// The changes recorded for it alone are pruned in `on_idle`. The origin must be
+		/// `AdminOrigin`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::remove_snapshot())]
+		pub fn remove_snapshot(
+			origin: OriginFor<T>,
+			#[pallet::compact] version: PolicyVersion,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Snapshots::<T>::try_mutate(|snapshots| -> DispatchResult {
+				let index = snapshots
+					.iter()
+					.position(|(snapshot, _)| *snapshot == version)
+					.ok_or(Error::<T>::SnapshotNotFound)?;
+				snapshots.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PolicySnapshotRemoved { version });
+			Ok(())
+		}
+
+		/// Roll the policy back to the snapshot of `version`.
+		///
+		/// Snapshots of later versions are removed. The changes made since the snapshot are undone
+		/// in `on_idle`, one version at a time, and the policy may not change until they all are.
+		/// Roles created since the snapshot are kept if they are still assigned or covered by a
+		/// separation of duty constraint. The origin must be root.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::rollback_to())]
+		pub fn rollback_to(
+			origin: OriginFor<T>,
+			#[pallet::compact] version: PolicyVersion,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(!PendingRollback::<T>::exists(), Error::<T>::RollbackInProgress);
+			Snapshots::<T>::try_mutate(|snapshots| -> DispatchResult {
+				ensure!(
+					snapshots.iter().any(|(snapshot, _)| *snapshot == version),
+					Error::<T>::SnapshotNotFound
+				);
+				snapshots.retain(|(snapshot, _)| *snapshot <= version);
+				Ok(())
+			})?;
+			let from = CurrentPolicyVersion::<T>::get();
+			PendingRollback::<T>::put(Rollback { from, to: version, changes: 0, kept_roles: 0 });
+
+			Self::deposit_event(Event::PolicyRollbackStarted { from, to: version });
+			Ok(())
+		}
+
+		/// Apply every operation of `batch` in order, or none of them.
+		///
+		/// Each operation is checked as the call of the same name would check it, and the policy
+		/// changes of the batch make up a single version. The origin must be `AdminOrigin` or hold
+		/// the admin roles of every role the batch operates on.
+		#[pallet::call_index(29)]
+		#[pallet::weight(Pallet::<T>::policy_batch_weight(batch))]
+		pub fn apply_policy_batch(origin: OriginFor<T>, batch: PolicyBatch<T>) -> DispatchResult {
+			ensure!(!batch.is_empty(), Error::<T>::EmptyPolicyBatch);
+			let mut who = None;
+			for op in batch.iter() {
+				who = Self::ensure_role_admin(origin.clone(), &op.roles())?;
+			}
+			if batch.iter().any(PolicyOp::changes_policy) {
+				Self::new_policy_version()?;
+			}
+
+			for op in batch.iter().cloned() {
+				match op {
+					PolicyOp::GrantPermission { role, permission } =>
+						Self::do_grant_permission(role, permission, who.as_ref()),
+					PolicyOp::RevokePermission { role, permission } =>
+						Self::do_revoke_permission(role, permission),
+					PolicyOp::AddDenyRule { role, permission } =>
+						Self::do_add_deny_rule(role, permission, who.as_ref()),
+					PolicyOp::RemoveDenyRule { role, permission } =>
+						Self::do_remove_deny_rule(role, permission),
+					PolicyOp::LinkRoles { senior, junior, holders } =>
+						Self::ensure_no_approval_required(who.as_ref(), junior)
+							.and_then(|_| Self::do_link_roles(senior, junior, holders)),
+					PolicyOp::UnlinkRoles { senior, junior } =>
+						Self::do_unlink_roles(senior, junior),
+					PolicyOp::AssignRole { account, role, expires_at } =>
+						Self::ensure_no_approval_required(who.as_ref(), role)
+							.and_then(|_| Self::do_assign_role(&account, role, expires_at)),
+					PolicyOp::RevokeRole { account, role } =>
+						Self::do_unassign_role(&account, role),
+				}?;
+			}
+
+			Self::deposit_event(Event::PolicyBatchApplied { batch, who });
+			Ok(())
+		}
+
+		/// Create a role template with the given permissions and deny rules.
+		///
+		/// The template is given the next available [`TemplateId`]. The origin must be
+		/// `AdminOrigin`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::create_template())]
+		pub fn create_template(
+			origin: OriginFor<T>,
+			name: RoleName<T>,
+			permissions: TemplatePermissions<T>,
+			deny_rules: TemplatePermissions<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_template(&permissions, &deny_rules)?;
+
+			let template = NextTemplateId::<T>::get();
+			let next = template.checked_add(1).ok_or(Error::<T>::TemplateIdOverflow)?;
+			Templates::<T>::insert(
+				template,
+				RoleTemplate { name, permissions, deny_rules, revision: 0, derived: 0 },
+			);
+			NextTemplateId::<T>::put(next);
+
+			Self::deposit_event(Event::TemplateCreated { template });
+			Ok(())
+		}
+
+		/// Replace the name, permissions and deny rules of a role template.
+		///
+		/// Roles tracking the template keep their permissions and deny rules until they are
+		/// synced with it. The origin must be `AdminOrigin`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::update_template())]
+		pub fn update_template(
+			origin: OriginFor<T>,
+			#[pallet::compact] template: TemplateId,
+			name: RoleName<T>,
+			permissions: TemplatePermissions<T>,
+			deny_rules: TemplatePermissions<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_template(&permissions, &deny_rules)?;
+
+			let revision = Templates::<T>::try_mutate(template, |maybe_template| {
+				let info = maybe_template.as_mut().ok_or(Error::<T>::TemplateNotFound)?;
+				info.name = name;
+				info.permissions = permissions;
+				info.deny_rules = deny_rules;
+				info.revision.saturating_inc();
+				Ok::<_, DispatchError>(info.revision)
+			})?;
+
+			Self::deposit_event(Event::TemplateUpdated { template, revision });
+			Ok(())
+		}
+
+		/// Remove a role template.
+		///
+		/// The template must not be tracked by any role. Roles created from it keep their
+		/// permissions and deny rules. The origin must be `AdminOrigin`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::remove_template())]
+		pub fn remove_template(
+			origin: OriginFor<T>,
+			#[pallet::compact] template: TemplateId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let info = Templates::<T>::get(template).ok_or(Error::<T>::TemplateNotFound)?;
+			ensure!(info.derived == 0, Error::<T>::TemplateInUse);
+			Templates::<T>::remove(template);
+
+			Self::deposit_event(Event::TemplateRemoved { template });
+			Ok(())
+		}
+
+		/// Create a new role with the given name and metadata, and the permissions and deny rules
+		/// of `template`.
+		///
+		/// If `track` is set, the role tracks the template, and may later be synced with its
+		/// updates by `sync_role`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::instantiate_template(T::MaxPermissionsPerRole::get()))]
+		pub fn instantiate_template(
+			origin: OriginFor<T>,
+			#[pallet::compact] template: TemplateId,
+			name: RoleName<T>,
+			metadata: RoleMetadata<T>,
+			track: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::new_policy_version()?;
+
+			let info = Templates::<T>::get(template).ok_or(Error::<T>::TemplateNotFound)?;
+			let (permissions, deny_rules) =
+				(info.permissions.len() as u32, info.deny_rules.len() as u32);
+			let role = Self::do_create_role(RoleInfo {
+				permissions,
+				deny_rules,
+				..RoleInfo::new(name, metadata)
+			})?;
+			Self::hold_deposit(Some(&who), role, DepositKind::Role, T::RoleDeposit::get())?;
+			Self::set_owner(role, Some(&who))?;
+			for permission in info.permissions {
+				Self::journal_permission(role, permission)?;
+				RolePermissions::<T>::insert(role, permission, ());
+				Self::hold_deposit(
+					Some(&who),
+					role,
+					DepositKind::Permission(permission),
+					T::PermissionDeposit::get(),
+				)?;
+			}
+			for permission in info.deny_rules {
+				Self::journal_deny_rule(role, permission)?;
+				RoleDenyRules::<T>::insert(role, permission, ());
+				Self::hold_deposit(
+					Some(&who),
+					role,
+					DepositKind::DenyRule(permission),
+					T::PermissionDeposit::get(),
+				)?;
+			}
+			if track {
+				Self::start_tracking(role, TemplateTracking { template, revision: info.revision });
+			}
+
+			Self::deposit_event(Event::RoleInstantiated { role, template, tracked: track, who });
+			Ok(Some(T::WeightInfo::instantiate_template(permissions + deny_rules)).into())
+		}
+
+		/// Create a new role with the given name and metadata as a copy of `source`.
+		///
+		/// The copy has the permissions of `source` along with their constraints and conditions,
+		/// its deny rules, admin role, limits, prerequisite and required approvals, and tracks the
+		/// same template, but no holders nor links. Frozen roles cannot be cloned. The origin must
+		/// be `AdminOrigin` or a holder of the admin role of `source`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::clone_role(T::MaxPermissionsPerRole::get()))]
+		pub fn clone_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] source: RoleId,
+			name: RoleName<T>,
+			metadata: RoleMetadata<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_role_admin(origin, &[source])?;
+			ensure!(!Self::is_frozen(source), Error::<T>::RoleIsFrozen);
+			Self::new_policy_version()?;
+
+			let info = Roles::<T>::get(source).ok_or(Error::<T>::RoleNotFound)?;
+			let rules = info.permissions + info.deny_rules;
+			let role = Self::do_create_role(RoleInfo { name, metadata, members: 0, ..info })?;
+			let depositor = who.as_ref();
+			Self::hold_deposit(depositor, role, DepositKind::Role, T::RoleDeposit::get())?;
+			Self::set_owner(role, depositor)?;
+			for permission in RolePermissions::<T>::iter_key_prefix(source) {
+				Self::journal_permission(role, permission)?;
+				RolePermissions::<T>::insert(role, permission, ());
+				Self::hold_deposit(
+					depositor,
+					role,
+					DepositKind::Permission(permission),
+					T::PermissionDeposit::get(),
+				)?;
+				if let Some(constraints) = PermissionConstraints::<T>::get(source, permission) {
+					PermissionConstraints::<T>::insert(role, permission, constraints);
+					Self::hold_deposit(
+						depositor,
+						role,
+						DepositKind::Constraints(permission),
+						T::ConstraintDeposit::get(),
+					)?;
+				}
+				if let Some(condition) = PermissionConditions::<T>::get(source, permission) {
+					PermissionConditions::<T>::insert(role, permission, condition);
+					Self::hold_deposit(
+						depositor,
+						role,
+						DepositKind::Condition(permission),
+						T::ConstraintDeposit::get(),
+					)?;
+				}
+			}
+			for permission in RoleDenyRules::<T>::iter_key_prefix(source) {
+				Self::journal_deny_rule(role, permission)?;
+				RoleDenyRules::<T>::insert(role, permission, ());
+				Self::hold_deposit(
+					depositor,
+					role,
+					DepositKind::DenyRule(permission),
+					T::PermissionDeposit::get(),
+				)?;
+			}
+			if let Some(tracking) = RoleTemplates::<T>::get(source) {
+				Self::start_tracking(role, tracking);
+			}
+			// The copy must not be a way around the approvals required to pass on `source`.
+			if let Some(approval) = GrantApprovals::<T>::get(source) {
+				GrantApprovals::<T>::insert(role, approval);
+				Self::deposit_event(Event::GrantApprovalSet { role, approval: Some(approval) });
+			}
+
+			Self::deposit_event(Event::RoleCloned { role, source, who });
+			Ok(Some(T::WeightInfo::clone_role(rules)).into())
+		}
+
+		/// Set the permissions and deny rules of `role` to those of the template it tracks.
+		///
+		/// Permissions the role keeps keep their constraints and conditions, revoked ones lose
+		/// them. The origin must be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::sync_role(T::MaxPermissionsPerRole::get()))]
+		pub fn sync_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+			Self::new_policy_version()?;
+			Self::journal_role(role)?;
+
+			let mut info = Roles::<T>::get(role).ok_or(Error::<T>::RoleNotFound)?;
+			let mut tracking = RoleTemplates::<T>::get(role).ok_or(Error::<T>::RoleNotTracking)?;
+			let template =
+				Templates::<T>::get(tracking.template).ok_or(Error::<T>::TemplateNotFound)?;
+			let rules = (info.permissions + info.deny_rules)
+				.max((template.permissions.len() + template.deny_rules.len()) as u32);
+
+			for permission in RolePermissions::<T>::iter_key_prefix(role).collect::<Vec<_>>() {
+				if !template.permissions.contains(&permission) {
+					Self::journal_permission(role, permission)?;
+					RolePermissions::<T>::remove(role, permission);
+					PermissionConstraints::<T>::remove(role, permission);
+					PermissionConditions::<T>::remove(role, permission);
+					Self::release_permission_deposits(role, permission);
+				}
+			}
+			for permission in template.permissions.iter().copied() {
+				if !RolePermissions::<T>::contains_key(role, permission) {
+					Self::journal_permission(role, permission)?;
+					RolePermissions::<T>::insert(role, permission, ());
+					Self::hold_deposit(
+						who.as_ref(),
+						role,
+						DepositKind::Permission(permission),
+						T::PermissionDeposit::get(),
+					)?;
+				}
+			}
+			for permission in RoleDenyRules::<T>::iter_key_prefix(role).collect::<Vec<_>>() {
+				if !template.deny_rules.contains(&permission) {
+					Self::journal_deny_rule(role, permission)?;
+					RoleDenyRules::<T>::remove(role, permission);
+					Self::release_deposit(role, DepositKind::DenyRule(permission));
+				}
+			}
+			for permission in template.deny_rules.iter().copied() {
+				if !RoleDenyRules::<T>::contains_key(role, permission) {
+					Self::journal_deny_rule(role, permission)?;
+					RoleDenyRules::<T>::insert(role, permission, ());
+					Self::hold_deposit(
+						who.as_ref(),
+						role,
+						DepositKind::DenyRule(permission),
+						T::PermissionDeposit::get(),
+					)?;
+				}
+			}
+			info.permissions = template.permissions.len() as u32;
+			info.deny_rules = template.deny_rules.len() as u32;
+			Roles::<T>::insert(role, info);
+			tracking.revision = template.revision;
+			RoleTemplates::<T>::insert(role, tracking);
+
+			Self::deposit_event(Event::RoleSynced {
+				role,
+				template: tracking.template,
+				revision: tracking.revision,
+				who,
+			});
+			Ok(Some(T::WeightInfo::sync_role(rules)).into())
+		}
+
+		/// Make `role` stop tracking its template, keeping its permissions and deny rules.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the role's admin role.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::untrack_template())]
+		pub fn untrack_template(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			let who = Self::ensure_role_admin(origin, &[role])?;
+
+			let tracking = Self::stop_tracking(role).ok_or(Error::<T>::RoleNotTracking)?;
+
+			Self::deposit_event(Event::RoleUntracked { role, template: tracking.template, who });
+			Ok(())
+		}
+
+		/// Propose `owner` as the new owner of `role`, who becomes it by accepting, or withdraw
+		/// the proposal.
+		///
+		/// The origin must be `AdminOrigin` or the owner of the role, or, if the role has no
+		/// owner, a holder of the role's admin role.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::propose_owner())]
+		pub fn propose_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			owner: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let who = Self::ensure_role_owner(origin, role)?;
+			let owner = owner.map(T::Lookup::lookup).transpose()?;
+
+			ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			Self::set_pending_owner(role, owner.as_ref())?;
+
+			Self::deposit_event(Event::RoleOwnerProposed { role, owner, who });
+			Ok(())
+		}
+
+		/// Become the owner of `role`, as proposed by `propose_owner`.
+		///
+		/// The deposit held for the role, if any, is then held from the new owner instead of the
+		/// account that made it.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				PendingOwners::<T>::get(role) == Some(who.clone()),
+				Error::<T>::NotPendingOwner
+			);
+			ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			Self::set_pending_owner(role, None)?;
+			Self::set_owner(role, Some(&who))?;
+			Self::move_deposit(role, DepositKind::Role, &who)?;
+
+			Self::deposit_event(Event::RoleOwnerChanged { role, owner: Some(who) });
+			Ok(())
+		}
+
+		/// Freeze `role`: its permissions, and the permissions it inherits, no longer allow any
+		/// call, and it no longer confers admin rights, until it is thawed.
+		///
+		/// Its assignments, delegations and deny rules are kept. The origin must be
+		/// `IncidentOrigin`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::freeze_role())]
+		pub fn freeze_role(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+		) -> DispatchResult {
+			T::IncidentOrigin::ensure_origin(origin)?;
+
+			ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			ensure!(!FrozenRoles::<T>::contains_key(role), Error::<T>::AlreadyFrozen);
+			FrozenRoles::<T>::insert(role, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::RoleFrozen { role });
+			Ok(())
+		}
+
+		/// Thaw a frozen `role`, restoring its permissions.
+		///
+		/// The origin must be `IncidentOrigin`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::thaw_role())]
+		pub fn thaw_role(origin: OriginFor<T>, #[pallet::compact] role: RoleId) -> DispatchResult {
+			T::IncidentOrigin::ensure_origin(origin)?;
+
+			ensure!(FrozenRoles::<T>::take(role).is_some(), Error::<T>::NotFrozen);
+
+			Self::deposit_event(Event::RoleThawed { role });
+			Ok(())
+		}
+
+		/// Set how [`CheckAccess`] enforces access decisions on signed transactions.
+		///
+		/// The origin must be root.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::set_enforcement_mode())]
+		pub fn set_enforcement_mode(origin: OriginFor<T>, mode: EnforcementMode) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Enforcement::<T>::put(mode);
+
+			Self::deposit_event(Event::EnforcementModeSet { mode });
+			Ok(())
+		}
+
+		/// Designate `account` for break-glass access to `role`, or remove its designation.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::set_break_glass_role())]
+		pub fn set_break_glass_role(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			role: Option<RoleId>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let account = T::Lookup::lookup(account)?;
+
+			if let Some(role) = role {
+				ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			}
+			BreakGlassRoles::<T>::set(&account, role);
+
+			Self::deposit_event(Event::BreakGlassRoleSet { account, role });
+			Ok(())
+		}
+
+		/// Break the glass: assign the break-glass role of the origin to it and activate it in its
+		/// session for `BreakGlassPeriod` blocks.
+		///
+		/// The use must then be reviewed by another account with `review_break_glass`, and the
+		/// origin may not break the glass again until it is.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::break_glass())]
+		pub fn break_glass(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let role = BreakGlassRoles::<T>::get(&who).ok_or(Error::<T>::NotBreakGlassAccount)?;
+			ensure!(
+				!UnreviewedBreakGlass::<T>::contains_key(&who),
+				Error::<T>::BreakGlassUnreviewed
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(T::BreakGlassPeriod::get());
+			Self::do_assign_role(&who, role, Some(expires_at))?;
+			let mut authorized = Self::session_authorized_roles(&who);
+			authorized.extend(Self::delegated_roles(&who));
+			authorized.extend(Self::inherited_roles(role));
+			Self::ensure_separation_of_duty(&authorized, true)?;
+			SessionRoles::<T>::insert(&who, role, ());
+
+			let id = NextBreakGlassId::<T>::get();
+			let next = id.checked_add(1).ok_or(Error::<T>::BreakGlassIdOverflow)?;
+			BreakGlassUses::<T>::insert(
+				id,
+				BreakGlassUse { account: who.clone(), role, activated_at: now, expires_at },
+			);
+			UnreviewedBreakGlass::<T>::insert(&who, id);
+			NextBreakGlassId::<T>::put(next);
+
+			Self::deposit_event(Event::BreakGlassActivated { id, account: who, role, expires_at });
+			Ok(())
+		}
+
+		/// Close the review of the use `id` of break-glass access.
+		///
+		/// The origin must be `AdminOrigin` or a holder of the admin role of the break-glass role,
+		/// other than the account that broke the glass.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::review_break_glass())]
+		pub fn review_break_glass(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: BreakGlassId,
+		) -> DispatchResult {
+			let used = BreakGlassUses::<T>::get(id).ok_or(Error::<T>::BreakGlassUseNotFound)?;
+			let reviewer = Self::ensure_role_admin(origin, &[used.role])?;
+			ensure!(reviewer.as_ref() != Some(&used.account), Error::<T>::BreakGlassSelfReview);
+
+			BreakGlassUses::<T>::remove(id);
+			UnreviewedBreakGlass::<T>::remove(&used.account);
+
+			Self::deposit_event(Event::BreakGlassReviewed { id, account: used.account, reviewer });
+			Ok(())
+		}
+
+		/// Require `approval` for assigning `role`, or stop requiring approvals.
+		///
+		/// While approvals are required, only `AdminOrigin` may assign `role`, link it to a senior
+		/// role or have it delegated; other accounts propose its assignments with `propose_grant`.
+		/// Approvals cannot be required for a role that senior roles already inherit. The origin
+		/// must be `AdminOrigin`.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_grant_approval())]
+		pub fn set_grant_approval(
+			origin: OriginFor<T>,
+			#[pallet::compact] role: RoleId,
+			approval: Option<GrantApproval>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::new_policy_version()?;
+			Self::journal_grant_approval(role)?;
+
+			ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			if let Some(approval) = approval {
+				ensure!(Roles::<T>::contains_key(approval.approver), Error::<T>::RoleNotFound);
+				ensure!(
+					RoleSeniors::<T>::iter_key_prefix(role).next().is_none(),
+					Error::<T>::RoleHasSeniors
+				);
+				ensure!(
+					approval.threshold > 0 && approval.threshold <= T::MaxGrantApprovals::get(),
+					Error::<T>::InvalidThreshold
+				);
+			}
+			GrantApprovals::<T>::set(role, approval);
+
+			Self::deposit_event(Event::GrantApprovalSet { role, approval });
+			Ok(())
+		}
+
+		/// Propose assigning `role`, which requires approvals, to `account`, optionally until the
+		/// block `expires_at`.
+		///
+		/// The proposal can be approved for `GrantProposalLifetime` blocks. The origin must be a
+		/// holder of the role's admin role.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::propose_grant())]
+		pub fn propose_grant(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			#[pallet::compact] role: RoleId,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let proposer =
+				Self::ensure_role_admin(origin, &[role])?.ok_or(DispatchError::BadOrigin)?;
+			let account = T::Lookup::lookup(account)?;
+			ensure!(GrantApprovals::<T>::contains_key(role), Error::<T>::NoApprovalRequired);
+
+			let id = NextGrantProposalId::<T>::get();
+			let next = id.checked_add(1).ok_or(Error::<T>::GrantProposalIdOverflow)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let proposal = GrantProposal {
+				proposer: proposer.clone(),
+				account: account.clone(),
+				role,
+				assignment_expires_at: expires_at,
+				approvals: Default::default(),
+				expires_at: now.saturating_add(T::GrantProposalLifetime::get()),
+			};
+			GrantProposalExpiries::<T>::insert(proposal.expires_at, id, ());
+			GrantProposals::<T>::insert(id, proposal);
+			NextGrantProposalId::<T>::put(next);
+
+			Self::deposit_event(Event::GrantProposed { id, account, role, proposer });
+			Ok(())
+		}
+
+		/// Approve the grant proposal `id`, assigning its role once it has enough approvals.
+		///
+		/// The origin must hold the approver role of the proposed role, be neither the proposer nor
+		/// the proposed account, and not have approved the proposal yet.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::approve_grant())]
+		pub fn approve_grant(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: GrantProposalId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut proposal =
+				GrantProposals::<T>::get(id).ok_or(Error::<T>::GrantProposalNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < proposal.expires_at, Error::<T>::ProposalExpired);
+			let approval =
+				GrantApprovals::<T>::get(proposal.role).ok_or(Error::<T>::NoApprovalRequired)?;
+			ensure!(
+				who != proposal.proposer && who != proposal.account,
+				Error::<T>::ApproverNotIndependent
+			);
+			ensure!(Self::holds_or_inherits(&who, approval.approver), Error::<T>::NotApprover);
+			ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+
+			proposal
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyApprovals)?;
+			Self::deposit_event(Event::GrantApproved { id, approver: who });
+
+			if (proposal.approvals.len() as u32) < approval.threshold {
+				GrantProposals::<T>::insert(id, proposal);
+				return Ok(())
+			}
+			Self::do_remove_grant_proposal(id);
+			Self::do_assign_role(&proposal.account, proposal.role, proposal.assignment_expires_at)?;
+
+			Self::deposit_event(Event::GrantExecuted {
+				id,
+				account: proposal.account,
+				role: proposal.role,
+				expires_at: proposal.assignment_expires_at,
+			});
+			Ok(())
+		}
+
+		/// Reject the grant proposal `id`, removing it.
+		///
+		/// The origin must hold the approver role of the proposed role.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::reject_grant())]
+		pub fn reject_grant(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: GrantProposalId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proposal = GrantProposals::<T>::get(id).ok_or(Error::<T>::GrantProposalNotFound)?;
+			let approval =
+				GrantApprovals::<T>::get(proposal.role).ok_or(Error::<T>::NoApprovalRequired)?;
+			ensure!(Self::holds_or_inherits(&who, approval.approver), Error::<T>::NotApprover);
+
+			Self::do_remove_grant_proposal(id);
+
+			Self::deposit_event(Event::GrantRejected { id, who });
+			Ok(())
+		}
+
+		/// Cancel the grant proposal `id`, removing it.
+		///
+		/// The origin must be `AdminOrigin` or the proposer.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::cancel_grant())]
+		pub fn cancel_grant(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: GrantProposalId,
+		) -> DispatchResult {
+			let proposal = GrantProposals::<T>::get(id).ok_or(Error::<T>::GrantProposalNotFound)?;
+			let who = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			ensure!(
+				who.as_ref().map_or(true, |who| *who == proposal.proposer),
+				Error::<T>::NotProposer
+			);
+
+			Self::do_remove_grant_proposal(id);
+
+			Self::deposit_event(Event::GrantCancelled { id, who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The permission required to dispatch `call`.
+	pub fn permission_of(call: &<T as Config>::RuntimeCall) -> Option<Permission> {
+		Permission::from_call(call)
+	}
+
+	/// Whether `role` permits dispatching `call`: a permission granted to it, or to one of the
+	/// junior roles it inherits, covers `call` and none of their deny rules does.
+	pub fn role_permits(role: RoleId, call: &<T as Config>::RuntimeCall) -> bool {
+		Self::role_decision(role, call).is_allowed()
+	}
+
+	/// The most specific permission through which `role` permits dispatching `call`.
+	pub fn permitting_permission(
+		role: RoleId,
+		call: &<T as Config>::RuntimeCall,
+	) -> Option<Permission> {
+		match Self::role_decision(role, call) {
+			AccessDecision::Allowed { permission, .. } => Some(permission),
+			_ => None,
+		}
+	}
+
+	/// Whether the rules of `role`, and of the junior roles it inherits, allow dispatching `call`.
+	pub fn role_decision(role: RoleId, call: &<T as Config>::RuntimeCall) -> AccessDecision {
+		let Some(permission) = Self::permission_of(call) else {
+			return AccessDecision::NotPermitted
+		};
+		Self::decide(role, permission, Some(&Request::new(call, None)))
+	}
+
+	/// The permissions granted directly to `role`, from the most to the least specific.
+	pub fn permissions_of(role: RoleId) -> Vec<Permission> {
+		let mut permissions: Vec<_> = RolePermissions::<T>::iter_key_prefix(role).collect();
+		permissions.sort();
+		permissions
+	}
+
+	/// The deny rules of `role`, from the most to the least specific.
+	pub fn deny_rules_of(role: RoleId) -> Vec<Permission> {
+		let mut rules: Vec<_> = RoleDenyRules::<T>::iter_key_prefix(role).collect();
+		rules.sort();
+		rules
+	}
+
+	/// Whether `role` permits `permission` for some call, see [`Self::role_permits`].
+	fn role_grants(role: RoleId, permission: Permission) -> bool {
+		Self::decide(role, permission, None).is_allowed()
+	}
+
+	/// Whether the rules of `role`, and of the junior roles it inherits, allow `permission` for
+	/// `request`, see [`AccessDecision`].
+	///
+	/// Without a `request`, constraints and conditions are ignored.
+	fn decide(
+		role: RoleId,
+		permission: Permission,
+		request: Option<&Request<T>>,
+	) -> AccessDecision {
+		let roles = Self::inherited_roles(role);
+		if let Some((role, permission)) = Self::first_deny(&roles, permission) {
+			return AccessDecision::Denied { role, permission }
+		}
+		let granting = Self::granting_roles(role);
+		if let Some((role, permission)) = Self::first_allow(&granting, permission, request) {
+			return AccessDecision::Allowed { role, permission, delegation: None }
+		}
+		Self::frozen_allow(&roles, &granting, permission, request)
+	}
+
+	/// The decision for a `permission` that no role of `granting` allows:
+	/// [`AccessDecision::Frozen`] if one of `roles` allows it, [`AccessDecision::NotPermitted`]
+	/// otherwise.
+	fn frozen_allow(
+		roles: &BTreeSet<RoleId>,
+		granting: &BTreeSet<RoleId>,
+		permission: Permission,
+		request: Option<&Request<T>>,
+	) -> AccessDecision {
+		if roles == granting {
+			return AccessDecision::NotPermitted
+		}
+		match Self::first_allow(roles, permission, request) {
+			Some((role, permission)) => AccessDecision::Frozen { role, permission },
+			None => AccessDecision::NotPermitted,
+		}
+	}
+
+	/// The most specific deny rule covering `permission` among `roles`, with ties going to the
+	/// lowest role id.
+	fn first_deny(
+		roles: &BTreeSet<RoleId>,
+		permission: Permission,
+	) -> Option<(RoleId, Permission)> {
+		permission.covering().find_map(|covering| {
+			roles
+				.iter()
+				.find(|role| RoleDenyRules::<T>::contains_key(*role, covering))
+				.map(|role| (*role, covering))
+		})
+	}
+
+	/// The most specific permission covering `permission` among `roles` whose constraints and
+	/// condition `request` satisfies, with ties going to the lowest role id.
+	fn first_allow(
+		roles: &BTreeSet<RoleId>,
+		permission: Permission,
+		request: Option<&Request<T>>,
+	) -> Option<(RoleId, Permission)> {
+		permission.covering().find_map(|covering| {
+			roles
+				.iter()
+				.find(|role| {
+					RolePermissions::<T>::contains_key(*role, covering) &&
+						request.map_or(true, |request| {
+							Self::constraints_hold(**role, covering, request.call) &&
+								Self::condition_holds(**role, covering, request)
+						})
+				})
+				.map(|role| (*role, covering))
+		})
+	}
+
+	/// Whether `call` satisfies every constraint carried by `permission` of `role`.
+	fn constraints_hold(
+		role: RoleId,
+		permission: Permission,
+		call: &<T as Config>::RuntimeCall,
+	) -> bool {
+		PermissionConstraints::<T>::get(role, permission).map_or(true, |constraints| {
+			constraints
+				.iter()
+				.all(|constraint| T::ConstraintEvaluator::evaluate(constraint, call))
+		})
+	}
+
+	/// Whether the condition carried by `permission` of `role`, if any, holds for `request`.
+	///
+	/// The weight of evaluating the condition is added to the weight of `request`.
+	fn condition_holds(role: RoleId, permission: Permission, request: &Request<T>) -> bool {
+		let Some(condition) = PermissionConditions::<T>::get(role, permission) else { return true };
+		let weight = T::WeightInfo::evaluate_condition(condition.len());
+		request.weight.set(request.weight.get().saturating_add(weight));
+		Self::evaluate_condition(&condition, request.caller)
+	}
+
+	/// Evaluate `condition` for a call of `caller` at the current block.
+	///
+	/// Without a caller, predicates on the caller do not hold.
+	pub(crate) fn evaluate_condition(
+		condition: &Condition<T>,
+		caller: Option<&T::AccountId>,
+	) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut attributes = None;
+		condition.evaluate(|op| match (op, caller) {
+			(ConditionOp::BlockRange { from, to }, _) => *from <= now && now < *to,
+			(ConditionOp::FreeBalanceAbove(min), Some(caller)) =>
+				<T::Currency as fungible::Inspect<_>>::balance(caller) > *min,
+			(ConditionOp::AttributeEquals { key, value }, Some(caller)) => attributes
+				.get_or_insert_with(|| AccountAttributes::<T>::get(caller))
+				.iter()
+				.any(|(k, v)| k == key && v == value),
+			_ => false,
+		})
+	}
+
+	/// Whether `account` may dispatch `call`, and the rule that decided it.
+	///
+	/// The roles in scope are the roles active in the session of `account` and the roles of the
+	/// effective delegations it received, along with every role they inherit. A deny rule of any
+	/// of them denies the call. Otherwise the call is allowed by the roles of the session first,
+	/// then by the delegations in the order they were made, as long as every delegation up the
+	/// chain covers the call. Frozen roles, and the roles inherited through them, allow nothing.
+	pub fn access_decision(
+		account: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+	) -> AccessDecision {
+		Self::decide_access(account, &Request::new(call, Some(account)))
+	}
+
+	/// Whether `account` may dispatch the call of `request`, see [`Self::access_decision`].
+	fn decide_access(account: &T::AccountId, request: &Request<T>) -> AccessDecision {
+		let Some(permission) = Self::permission_of(request.call) else {
+			return AccessDecision::NotPermitted
+		};
+		let session = Self::session_authorized_roles(account);
+		let session_granting = Self::session_granting_roles(account);
+		let delegations: Vec<_> = DelegationsTo::<T>::iter_key_prefix(account)
+			.filter_map(|delegation| {
+				let chain = Self::delegation_chain(delegation)?;
+				let role = Delegations::<T>::get(delegation)?.role;
+				Some((delegation, chain, Self::inherited_roles(role), Self::granting_roles(role)))
+			})
+			.collect();
+
+		let mut scope = session.clone();
+		for (_, _, roles, _) in &delegations {
+			scope.extend(roles);
+		}
+		if let Some((role, permission)) = Self::first_deny(&scope, permission) {
+			return AccessDecision::Denied { role, permission }
+		}
+
+		if let Some((role, permission)) =
+			Self::first_allow(&session_granting, permission, Some(request))
+		{
+			return AccessDecision::Allowed { role, permission, delegation: None }
+		}
+		let delegated = |id| {
+			Delegations::<T>::get(id).map_or(false, |d| {
+				d.permissions.map_or(true, |p| p.iter().any(|p| p.covers(&permission)))
+			})
+		};
+		let delegations: Vec<_> = delegations
+			.into_iter()
+			.filter(|(_, chain, _, _)| chain.iter().all(|id| delegated(*id)))
+			.collect();
+		if let Some(decision) = delegations.iter().find_map(|(delegation, _, _, granting)| {
+			Self::first_allow(granting, permission, Some(request)).map(|(role, permission)| {
+				AccessDecision::Allowed { role, permission, delegation: Some(*delegation) }
+			})
+		}) {
+			return decision
+		}
+		[(&session, &session_granting)]
+			.into_iter()
+			.chain(delegations.iter().map(|(_, _, roles, granting)| (roles, granting)))
+			.map(|(roles, granting)| Self::frozen_allow(roles, granting, permission, Some(request)))
+			.find(|decision| *decision != AccessDecision::NotPermitted)
+			.unwrap_or(AccessDecision::NotPermitted)
+	}
+
+	/// Check whether `account` may dispatch `call` now, within the rate limit and the remaining
+	/// allowance of the role whose permission allows it.
+	///
+	/// Returns the decision along with the weight of the conditions evaluated to reach it.
+	pub fn check_access(
+		account: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+	) -> Result<(AccessDecision, Weight), Error<T>> {
+		let request = Request::new(call, Some(account));
+		let decision = Self::decide_access(account, &request);
+		let weight = request.weight.get();
+		let AccessDecision::Allowed { role, permission, .. } = decision else {
+			return Err(Error::<T>::AccessDenied)
+		};
+		let Some(info) = Roles::<T>::get(role) else { return Ok((decision, weight)) };
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(limit) = info.rate_limit {
+			let used = UsageWindows::<T>::get(account, permission)
+				.filter(|window| now < window.ends_at)
+				.map_or(0, |window| window.calls);
+			ensure!(used < limit.calls, Error::<T>::RateLimitExceeded);
+		}
+		if let Some(allowance) = info.allowance {
+			if let Some(spend) = T::SpendOf::spend_of(account, call) {
+				let remaining = AllowanceWindows::<T>::get(account, role)
+					.filter(|window| now < window.refills_at)
+					.map_or(allowance.amount, |window| window.remaining);
+				ensure!(spend <= remaining, Error::<T>::AllowanceExceeded);
+			}
+		}
+		Ok((decision, weight))
+	}
+
+	/// The allowance left to `account` in the current period of `role`, or `None` if the role
+	/// does not limit spending.
+	pub fn remaining_allowance(account: &T::AccountId, role: RoleId) -> Option<BalanceOf<T>> {
+		let allowance = Roles::<T>::get(role)?.allowance?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let window = AllowanceWindows::<T>::get(account, role).filter(|w| now < w.refills_at);
+		Some(window.map_or(allowance.amount, |window| window.remaining))
+	}
+
+	/// Charge a call of `account` allowed by `decision` to the rate limit of the permission and
+	/// to the allowance of the role allowing it, starting new windows where the current ones
+	/// ended.
+	pub(crate) fn record_access(
+		account: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		decision: &AccessDecision,
+	) {
+		let AccessDecision::Allowed { role, permission, .. } = *decision else { return };
+		let Some(info) = Roles::<T>::get(role) else { return };
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(limit) = info.rate_limit {
+			Self::record_usage(account, permission, limit, now);
+		}
+		if let Some(allowance) = info.allowance {
+			if let Some(spend) = T::SpendOf::spend_of(account, call) {
+				Self::spend_allowance(account, role, allowance, spend, now);
+			}
+		}
+	}
+
+	/// Count a call of `account` using `permission`, limited by `limit`.
+	fn record_usage(
+		account: &T::AccountId,
+		permission: Permission,
+		limit: RateLimit<BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) {
+		UsageWindows::<T>::mutate(account, permission, |window| match window {
+			Some(current) if now < current.ends_at => current.calls.saturating_inc(),
+			_ => {
+				if let Some(ended) = window {
+					UsageWindowExpiries::<T>::remove(ended.ends_at, (account, permission));
+				}
+				let ends_at = now.saturating_add(limit.period);
+				UsageWindowExpiries::<T>::insert(ends_at, (account, permission), ());
+				*window = Some(UsageWindow { calls: 1, ends_at });
+			},
+		});
+	}
+
+	/// Charge `spend` to the allowance of `account` for `role`.
+	fn spend_allowance(
+		account: &T::AccountId,
+		role: RoleId,
+		allowance: Allowance<BalanceOf<T>, BlockNumberFor<T>>,
+		spend: BalanceOf<T>,
+		now: BlockNumberFor<T>,
+	) {
+		let remaining = AllowanceWindows::<T>::mutate(account, role, |maybe_window| {
+			let mut window = match *maybe_window {
+				Some(window) if now < window.refills_at => window,
+				ended => {
+					if let Some(ended) = ended {
+						AllowanceRefills::<T>::remove(ended.refills_at, (account, role));
+					}
+					let refills_at = now.saturating_add(allowance.period);
+					AllowanceRefills::<T>::insert(refills_at, (account, role), ());
+					AllowanceWindow { remaining: allowance.amount, refills_at }
+				},
+			};
+			window.remaining = window.remaining.saturating_sub(spend);
+			*maybe_window = Some(window);
+			window.remaining
+		});
+		Self::deposit_event(Event::AllowanceSpent {
+			account: account.clone(),
+			role,
+			amount: spend,
+			remaining,
+		});
+	}
+
+	/// Whether `account` may dispatch `call`, see [`Self::access_decision`].
+	pub fn is_permitted(account: &T::AccountId, call: &<T as Config>::RuntimeCall) -> bool {
+		Self::access_decision(account, call).is_allowed()
+	}
+
+	/// The delegation chain through which `account` is permitted to dispatch `call`, from the
+	/// original delegation down to the one received by `account`.
+	///
+	/// Returns `None` if `account` is not permitted to dispatch `call` through a delegation,
+	/// including when a role active in its session already permits it.
+	pub fn delegation_chain_for(
+		account: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+	) -> Option<Vec<DelegationId>> {
+		match Self::access_decision(account, call) {
+			AccessDecision::Allowed { delegation: Some(delegation), .. } =>
+				Self::delegation_chain(delegation),
+			_ => None,
+		}
+	}
+
+	/// The chain of delegations ending with `delegation`, starting with the original delegation.
+	///
+	/// Returns `None` if `delegation` is not effective: it, or a delegation up its chain, was
+	/// revoked or expired, or the assignment backing the original delegation was.
+	pub fn delegation_chain(delegation: DelegationId) -> Option<Vec<DelegationId>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut chain = Vec::new();
+		let mut next = Some(delegation);
+		while let Some(delegation) = next {
+			if chain.len() as u32 >= T::MaxDelegationDepth::get() {
+				return None
+			}
+			let info = Delegations::<T>::get(delegation)?;
+			if now >= info.expires_at {
+				return None
+			}
+			if info.parent.is_none() && !Self::has_role(&info.delegator, info.role) {
+				return None
+			}
+			chain.push(delegation);
+			next = info.parent;
+		}
+		chain.reverse();
+		Some(chain)
+	}
+
+	/// The roles whose permissions are delegated to `account` by effective delegations, see
+	/// [`Self::roles_delegated_by`].
+	pub fn delegated_roles(account: &T::AccountId) -> BTreeSet<RoleId> {
+		DelegationsTo::<T>::iter_key_prefix(account)
+			.filter(|delegation| Self::delegation_chain(*delegation).is_some())
+			.filter_map(|delegation| Delegations::<T>::get(delegation))
+			.flat_map(|delegation| {
+				Self::roles_delegated_by(delegation.role, delegation.permissions.as_ref())
+			})
+			.collect()
+	}
+
+	/// The roles whose permissions a delegation of `role` passes on: every role `role` inherits,
+	/// or only those granting a permission overlapping one of `permissions` if it delegates some
+	/// permissions only.
+	pub fn roles_delegated_by(
+		role: RoleId,
+		permissions: Option<&DelegatedPermissions<T>>,
+	) -> BTreeSet<RoleId> {
+		let roles = Self::inherited_roles(role);
+		let Some(permissions) = permissions else { return roles };
+		roles
+			.into_iter()
+			.filter(|role| {
+				RolePermissions::<T>::iter_key_prefix(role).any(|granted| {
+					permissions.iter().any(|p| p.covers(&granted) || granted.covers(p))
+				})
+			})
+			.collect()
+	}
+
+	/// The roles directly inherited by `role`.
+	pub fn juniors_of(role: RoleId) -> Vec<RoleId> {
+		RoleJuniors::<T>::iter_key_prefix(role).collect()
+	}
+
+	/// Whether one of the roles active in the session of `account` is `role` or inherits it,
+	/// other than through a frozen role.
+	pub fn holds_or_inherits(account: &T::AccountId, role: RoleId) -> bool {
+		Self::session_roles(account).any(|held| Self::granting_roles(held).contains(&role))
+	}
+
+	/// The role whose holders administer `role`, if any.
+	pub fn admin_of(role: RoleId) -> Option<RoleId> {
+		Roles::<T>::get(role)?.admin.or_else(BootstrapAdmin::<T>::get)
+	}
+
+	/// Ensure that `origin` may manage every role in `roles`.
+	///
+	/// Returns `None` for `AdminOrigin` and the signer otherwise.
+	fn ensure_role_admin(
+		origin: OriginFor<T>,
+		roles: &[RoleId],
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		let who = match T::AdminOrigin::try_origin(origin) {
+			Ok(_) => return Ok(None),
+			Err(origin) => ensure_signed(origin)?,
+		};
+		for role in roles {
+			ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+			let admin = Self::admin_of(*role).ok_or(Error::<T>::NotRoleAdmin)?;
+			ensure!(Self::holds_or_inherits(&who, admin), Error::<T>::NotRoleAdmin);
+		}
+		Ok(Some(who))
+	}
+
+	/// Ensure that `origin` may manage the ownership of `role`, which its owner may, or its
+	/// admins if it has none.
+	///
+	/// Returns `None` for `AdminOrigin` and the signer otherwise.
+	fn ensure_role_owner(
+		origin: OriginFor<T>,
+		role: RoleId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		let Some(owner) = RoleOwners::<T>::get(role) else {
+			return Self::ensure_role_admin(origin, &[role])
+		};
+		let who = match T::AdminOrigin::try_origin(origin) {
+			Ok(_) => return Ok(None),
+			Err(origin) => ensure_signed(origin)?,
+		};
+		ensure!(who == owner, Error::<T>::NotRoleOwner);
+		Ok(Some(who))
+	}
+
+	/// Ensure that `origin` is `AdminOrigin`, the owner of `role` or may manage `role`.
+	///
+	/// Returns `None` for `AdminOrigin` and the signer otherwise.
+	fn ensure_owner_or_role_admin(
+		origin: OriginFor<T>,
+		role: RoleId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		Self::ensure_role_owner(origin.clone(), role)
+			.or_else(|_| Self::ensure_role_admin(origin, &[role]))
+	}
+
+	/// Make `owner` the owner of `role`, or leave `role` without owner.
+	fn set_owner(role: RoleId, owner: Option<&T::AccountId>) -> DispatchResult {
+		if RoleOwners::<T>::get(role).as_ref() == owner {
+			return Ok(())
+		}
+		if let Some(owner) = owner {
+			OwnedRoleCount::<T>::try_mutate(owner, |count| -> DispatchResult {
+				ensure!(*count < T::MaxOwnedRoles::get(), Error::<T>::TooManyOwnedRoles);
+				*count += 1;
+				Ok(())
+			})?;
+			OwnedRoles::<T>::insert(owner, role, ());
+		}
+		if let Some(previous) =
+			RoleOwners::<T>::mutate(role, |current| sp_std::mem::replace(current, owner.cloned()))
+		{
+			OwnedRoles::<T>::remove(&previous, role);
+			OwnedRoleCount::<T>::mutate_exists(&previous, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+		Ok(())
+	}
+
+	/// Propose `owner` as the new owner of `role`, or withdraw the proposal.
+	///
+	/// An account may not be proposed for more roles than `MaxOwnedRoles`.
+	fn set_pending_owner(role: RoleId, owner: Option<&T::AccountId>) -> DispatchResult {
+		if let Some(previous) = PendingOwners::<T>::take(role) {
+			PendingOwnedRoles::<T>::remove(&previous, role);
+		}
+		if let Some(owner) = owner {
+			ensure!(
+				(PendingOwnedRoles::<T>::iter_key_prefix(owner).count() as u32) <
+					T::MaxOwnedRoles::get(),
+				Error::<T>::TooManyOwnedRoles
+			);
+			PendingOwnedRoles::<T>::insert(owner, role, ());
+			PendingOwners::<T>::insert(role, owner);
+		}
+		Ok(())
+	}
+
+	/// The roles inherited by `role`, including `role` itself.
+	pub fn inherited_roles(role: RoleId) -> BTreeSet<RoleId> {
+		Self::reachable_roles(role, |role| RoleJuniors::<T>::iter_key_prefix(role).collect())
+	}
+
+	/// The roles inherited by `role`, including `role` itself, whose permissions it grants: a
+	/// frozen role grants nothing, not even the permissions of the roles it inherits.
+	pub fn granting_roles(role: RoleId) -> BTreeSet<RoleId> {
+		let mut roles = BTreeSet::new();
+		let mut frontier = Vec::from([role]);
+		for _ in 0..=T::MaxHierarchyDepth::get() {
+			let next: Vec<_> = frontier
+				.into_iter()
+				.filter(|role| !Self::is_frozen(*role) && roles.insert(*role))
+				.flat_map(|role| RoleJuniors::<T>::iter_key_prefix(role))
+				.collect();
+			if next.is_empty() {
+				break
+			}
+			frontier = next;
+		}
+		roles
+	}
+
+	/// Whether `role` is frozen.
+	pub fn is_frozen(role: RoleId) -> bool {
+		FrozenRoles::<T>::contains_key(role)
+	}
+
+	/// The roles inheriting `role`, including `role` itself.
+	pub fn inheriting_roles(role: RoleId) -> BTreeSet<RoleId> {
+		Self::reachable_roles(role, |role| RoleSeniors::<T>::iter_key_prefix(role).collect())
+	}
+
+	/// `role` and the roles reached from it through at most `MaxHierarchyDepth` `links`, walked
+	/// breadth first so that each role is visited once however many paths lead to it.
+	fn reachable_roles(role: RoleId, links: impl Fn(RoleId) -> Vec<RoleId>) -> BTreeSet<RoleId> {
+		let mut roles = BTreeSet::from([role]);
+		let mut frontier = Vec::from([role]);
+		for _ in 0..T::MaxHierarchyDepth::get() {
+			let mut next = Vec::new();
+			for role in frontier {
+				next.extend(links(role).into_iter().filter(|linked| roles.insert(*linked)));
+			}
+			if next.is_empty() {
+				break
+			}
+			frontier = next;
+		}
+		roles
+	}
+
+	/// The roles `account` is authorized for: its active roles and every role they inherit.
+	pub fn authorized_roles(account: &T::AccountId) -> BTreeSet<RoleId> {
+		Self::active_roles(account).flat_map(Self::inherited_roles).collect()
+	}
+
+	/// The roles active in the session of `account` and every role they inherit.
+	pub fn session_authorized_roles(account: &T::AccountId) -> BTreeSet<RoleId> {
+		Self::session_roles(account).flat_map(Self::inherited_roles).collect()
+	}
+
+	/// The roles active in the session of `account` and every role they inherit whose
+	/// permissions they grant, see [`Self::granting_roles`].
+	pub fn session_granting_roles(account: &T::AccountId) -> BTreeSet<RoleId> {
+		Self::session_roles(account).flat_map(Self::granting_roles).collect()
+	}
+
+	/// Ensure that being authorized for `authorized` roles satisfies every static separation of
+	/// duty constraint and, if the roles are `active` in a session, every dynamic one.
+	fn ensure_separation_of_duty(authorized: &BTreeSet<RoleId>, active: bool) -> DispatchResult {
+		let constraints: BTreeSet<ConstraintId> = authorized
+			.iter()
+			.flat_map(|role| RoleConstraints::<T>::iter_key_prefix(role))
+			.collect();
+		for constraint in constraints {
+			let Some(constraint) = SodConstraints::<T>::get(constraint) else { continue };
+			if constraint.kind == SodKind::Dynamic && !active {
+				continue
+			}
+			let held = constraint.roles.iter().filter(|role| authorized.contains(role)).count();
+			ensure!(held as u32 <= constraint.max_held, Error::<T>::SeparationOfDutyViolated);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `account` satisfies the separation of duty constraints while the roles in
+	/// `delegated` are delegated to it.
+	///
+	/// Delegated roles are in effect without being activated, so they are checked against dynamic
+	/// constraints along with the roles active in the session of `account`.
+	fn ensure_account_separation_of_duty(
+		account: &T::AccountId,
+		delegated: BTreeSet<RoleId>,
+	) -> DispatchResult {
+		let mut authorized = Self::authorized_roles(account);
+		authorized.extend(delegated.iter().copied());
+		Self::ensure_separation_of_duty(&authorized, false)?;
+		let mut active = Self::session_authorized_roles(account);
+		active.extend(delegated);
+		Self::ensure_separation_of_duty(&active, true)
+	}
+
+	/// Ensure that every role in `roles`, every account holding one of them and every delegate of
+	/// one of them satisfy the separation of duty constraints, checking at most `holders`
+	/// assignments and delegations.
+	///
+	/// A role inheriting conflicting roles could never be activated, so roles are checked against
+	/// dynamic constraints as well.
+	fn ensure_holders_respect_constraints(roles: BTreeSet<RoleId>, holders: u32) -> DispatchResult {
+		let mut checked = roles
+			.iter()
+			.filter_map(|role| Roles::<T>::get(role))
+			.fold(0u32, |total, info| total.saturating_add(info.members));
+		ensure!(checked <= holders, Error::<T>::HoldersWitnessTooLow);
+		for role in roles {
+			Self::ensure_separation_of_duty(&Self::inherited_roles(role), true)?;
+			for account in RoleMembers::<T>::iter_key_prefix(role) {
+				Self::ensure_account_separation_of_duty(&account, Self::delegated_roles(&account))?;
+			}
+			for delegation in RoleDelegations::<T>::iter_key_prefix(role) {
+				checked.saturating_inc();
+				ensure!(checked <= holders, Error::<T>::HoldersWitnessTooLow);
+				if Self::delegation_chain(delegation).is_none() {
+					continue
+				}
+				let Some(info) = Delegations::<T>::get(delegation) else { continue };
+				let delegated = Self::delegated_roles(&info.delegate);
+				Self::ensure_account_separation_of_duty(&info.delegate, delegated)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Whether `account` holds an active assignment of `role`.
+	pub fn has_role(account: &T::AccountId, role: RoleId) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		AccountRoles::<T>::get(account, role).map_or(false, |assignment| assignment.is_active(&now))
+	}
+
+	/// The roles actively held by `account`.
+	pub fn roles_of(account: &T::AccountId) -> Vec<RoleId> {
+		Self::active_roles(account).collect()
+	}
+
+	/// The roles of `account` whose assignment has not expired.
+	fn active_roles(account: &T::AccountId) -> impl Iterator<Item = RoleId> {
+		let now = frame_system::Pallet::<T>::block_number();
+		AccountRoles::<T>::iter_prefix(account)
+			.filter_map(move |(role, assignment)| assignment.is_active(&now).then_some(role))
+	}
+
+	/// The roles active in the session of `account`.
+	pub fn session_of(account: &T::AccountId) -> Vec<RoleId> {
+		Self::session_roles(account).collect()
+	}
+
+	/// The roles active in the session of `account` whose assignment has not expired.
+	fn session_roles(account: &T::AccountId) -> impl Iterator<Item = RoleId> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let account = account.clone();
+		SessionRoles::<T>::iter_key_prefix(&account).filter(move |role| {
+			AccountRoles::<T>::get(&account, role).map_or(false, |a| a.is_active(&now))
+		})
+	}
+
+	/// The accounts holding `role`.
+	pub fn members_of(role: RoleId) -> Vec<T::AccountId> {
+		RoleMembers::<T>::iter_key_prefix(role).collect()
+	}
+
+	/// Length of the longest chain of links starting at `role` and following `links`, looking at
+	/// most `depth` links deep.
+	///
+	/// The roles at each distance are walked once per distance, so that the walk stays linear in
+	/// the number of roles reached however many paths lead to them.
+	fn longest_chain(role: RoleId, depth: u32, links: impl Fn(RoleId) -> Vec<RoleId>) -> u32 {
+		let mut frontier = BTreeSet::from([role]);
+		for length in 0..depth {
+			let next: BTreeSet<_> = frontier.into_iter().flat_map(&links).collect();
+			if next.is_empty() {
+				return length
+			}
+			frontier = next;
+		}
+		depth
+	}
+
+	/// Ensure that linking `senior` to `junior` keeps the hierarchy acyclic and within
+	/// `MaxHierarchyDepth`.
+	fn ensure_can_link(senior: RoleId, junior: RoleId) -> DispatchResult {
+		let max_depth = T::MaxHierarchyDepth::get();
+		ensure!(!Self::inherited_roles(junior).contains(&senior), Error::<T>::HierarchyCycle);
+		let above = Self::longest_chain(senior, max_depth, |role| {
+			RoleSeniors::<T>::iter_key_prefix(role).collect()
+		});
+		let below = Self::longest_chain(junior, max_depth, |role| {
+			RoleJuniors::<T>::iter_key_prefix(role).collect()
+		});
+		ensure!(above.saturating_add(below) < max_depth, Error::<T>::HierarchyTooDeep);
+		Ok(())
+	}
+
+	/// Ensure that `account` holds `prerequisite` at least until `expires_at`.
+	fn ensure_prerequisite_held(
+		account: &T::AccountId,
+		prerequisite: RoleId,
+		expires_at: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let held = AccountRoles::<T>::get(account, prerequisite)
+			.filter(|assignment| assignment.is_active(&now))
+			.ok_or(Error::<T>::PrerequisiteNotHeld)?;
+		let long_enough = match (held.expires_at, expires_at) {
+			(None, _) => true,
+			(Some(held), Some(expires_at)) => expires_at <= held,
+			(Some(_), None) => false,
+		};
+		ensure!(long_enough, Error::<T>::PrerequisiteNotHeld);
+		Ok(())
+	}
+
+	/// Ensure that `prerequisite` may be required to hold `role`.
+	///
+	/// An account holds at most `MaxRolesPerAccount` roles, so no chain of prerequisites may be
+	/// longer, and no chain may lead back to `role`.
+	fn ensure_valid_prerequisite(role: RoleId, prerequisite: RoleId) -> DispatchResult {
+		let mut next = Some(prerequisite);
+		let mut length = 1;
+		while let Some(current) = next {
+			ensure!(
+				current != role && length < T::MaxRolesPerAccount::get(),
+				Error::<T>::InvalidPrerequisite
+			);
+			next = Roles::<T>::get(current).ok_or(Error::<T>::RoleNotFound)?.prerequisite;
+			length += 1;
+		}
+		Ok(())
+	}
+
+	/// Remove the assignment of `role` to `account` from every index and from its session, along
+	/// with the delegations it backs.
+	fn do_revoke_role(account: &T::AccountId, role: RoleId) {
+		let Some(assignment) = AccountRoles::<T>::take(account, role) else { return };
+		if let Some(expires_at) = assignment.expires_at {
+			RoleExpiries::<T>::remove(expires_at, (account, role));
+		}
+		RoleMembers::<T>::remove(role, account);
+		SessionRoles::<T>::remove(account, role);
+		Roles::<T>::mutate(role, |info| {
+			if let Some(info) = info {
+				info.members.saturating_dec();
+			}
+		});
+		AccountRoleCount::<T>::mutate_exists(account, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+		// Delegations derived from the removed ones are no longer effective either, and are
+		// removed once they expire.
+		let backed: Vec<DelegationId> = DelegationsBy::<T>::iter_key_prefix(account)
+			.filter(|delegation| {
+				Delegations::<T>::get(delegation)
+					.map_or(false, |d| d.role == role && d.parent.is_none())
+			})
+			.collect();
+		for delegation in backed {
+			Self::do_remove_delegation(delegation);
+		}
+	}
+
+	/// Remove `delegation` from every index.
+	fn do_remove_delegation(delegation: DelegationId) {
+		let Some(info) = Delegations::<T>::take(delegation) else { return };
+		DelegationsBy::<T>::remove(&info.delegator, delegation);
+		DelegationsTo::<T>::remove(&info.delegate, delegation);
+		RoleDelegations::<T>::remove(info.role, delegation);
+		DelegationExpiries::<T>::remove(info.expires_at, delegation);
+	}
+
+	/// Ensure that `delegate` may be delegated the whole of `role` until `expires_at`: it must hold
+	/// the prerequisite of `role`, and there must be room for it within `max_members`.
+	fn ensure_can_hold_delegated(
+		delegate: &T::AccountId,
+		role: RoleId,
+		expires_at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let info = Roles::<T>::get(role).ok_or(Error::<T>::RoleNotFound)?;
+		if let Some(prerequisite) = info.prerequisite {
+			Self::ensure_prerequisite_held(delegate, prerequisite, Some(expires_at))?;
+		}
+		if let Some(max) = info.max_members {
+			let delegates = Self::delegates_of(role);
+			ensure!(
+				RoleMembers::<T>::contains_key(role, delegate) ||
+					delegates.contains(delegate) ||
+					info.members.saturating_add(delegates.len() as u32) < max,
+				Error::<T>::TooManyMembers
+			);
+		}
+		Ok(())
+	}
+
+	/// The accounts `role` is delegated to as a whole by effective delegations, other than its
+	/// holders.
+	pub fn delegates_of(role: RoleId) -> BTreeSet<T::AccountId> {
+		RoleDelegations::<T>::iter_key_prefix(role)
+			.filter(|delegation| Self::delegation_chain(*delegation).is_some())
+			.filter_map(|delegation| Delegations::<T>::get(delegation))
+			.filter(|delegation| delegation.permissions.is_none())
+			.map(|delegation| delegation.delegate)
+			.filter(|delegate| !RoleMembers::<T>::contains_key(role, delegate))
+			.collect()
+	}
+
+	/// Ensure that `who` may pass `role` on without approvals, as `AdminOrigin` (`None`) always
+	/// may.
+	fn ensure_no_approval_required(who: Option<&T::AccountId>, role: RoleId) -> DispatchResult {
+		ensure!(
+			who.is_none() || !GrantApprovals::<T>::contains_key(role),
+			Error::<T>::ApprovalRequired
+		);
+		Ok(())
+	}
+
+	/// Remove the grant proposal `id` from every index.
+	fn do_remove_grant_proposal(id: GrantProposalId) {
+		let Some(proposal) = GrantProposals::<T>::take(id) else { return };
+		GrantProposalExpiries::<T>::remove(proposal.expires_at, id);
+	}
+
+	/// Remove assignments, delegations, usage windows, allowance windows and grant proposals
+	/// that expired at or before `now`, using at most `limit` weight.
+	///
+	/// Blocks are processed in order starting at [`ExpiryCursor`], which only moves past a block
+	/// once everything expiring at that block has been removed.
+	fn remove_expired(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut used = db.reads_writes(1, 1);
+		if used.any_gt(limit) {
+			return Weight::zero()
+		}
+
+		let mut cursor = ExpiryCursor::<T>::get();
+		while cursor <= now {
+			if let Some((account, role)) = RoleExpiries::<T>::iter_key_prefix(cursor).next() {
+				let weight = T::WeightInfo::expire_role();
+				if used.saturating_add(weight).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(weight);
+				Self::do_revoke_role(&account, role);
+				Self::deposit_event(Event::RoleExpired { role, account });
+			} else if let Some(delegation) = DelegationExpiries::<T>::iter_key_prefix(cursor).next()
+			{
+				let weight = T::WeightInfo::expire_delegation();
+				if used.saturating_add(weight).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(weight);
+				Self::do_remove_delegation(delegation);
+				Self::deposit_event(Event::DelegationExpired { delegation });
+			} else if let Some((account, permission)) =
+				UsageWindowExpiries::<T>::iter_key_prefix(cursor).next()
+			{
+				let weight = T::WeightInfo::expire_usage_window();
+				if used.saturating_add(weight).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(weight);
+				UsageWindowExpiries::<T>::remove(cursor, (&account, permission));
+				UsageWindows::<T>::remove(&account, permission);
+			} else if let Some((account, role)) =
+				AllowanceRefills::<T>::iter_key_prefix(cursor).next()
+			{
+				let weight = T::WeightInfo::expire_allowance_window();
+				if used.saturating_add(weight).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(weight);
+				AllowanceRefills::<T>::remove(cursor, (&account, role));
+				AllowanceWindows::<T>::remove(&account, role);
+			} else if let Some(id) = GrantProposalExpiries::<T>::iter_key_prefix(cursor).next() {
+				let weight = T::WeightInfo::expire_grant_proposal();
+				if used.saturating_add(weight).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(weight);
+				Self::do_remove_grant_proposal(id);
+				Self::deposit_event(Event::GrantProposalExpired { id });
+			} else {
+				if used.saturating_add(db.reads(5)).any_gt(limit) {
+					break
+				}
+				used.saturating_accrue(db.reads(5));
+				cursor.saturating_inc();
+			}
+		}
+		ExpiryCursor::<T>::put(cursor);
+		used
+	}
+
+	/// The weight of applying `batch`, as the sum of the weights of the calls its operations
+	/// stand for.
+	pub fn policy_batch_weight(batch: &PolicyBatch<T>) -> Weight {
+		batch.iter().fold(Weight::zero(), |weight, op| {
+			weight.saturating_add(match op {
+				PolicyOp::GrantPermission { .. } => T::WeightInfo::grant_permission(),
+				PolicyOp::RevokePermission { .. } => T::WeightInfo::revoke_permission(),
+				PolicyOp::AddDenyRule { .. } => T::WeightInfo::add_deny_rule(),
+				PolicyOp::RemoveDenyRule { .. } => T::WeightInfo::remove_deny_rule(),
+				PolicyOp::LinkRoles { holders, .. } =>
+					T::WeightInfo::link_roles(T::MaxHierarchyDepth::get(), *holders),
+				PolicyOp::UnlinkRoles { .. } => T::WeightInfo::unlink_roles(),
+				PolicyOp::AssignRole { .. } => T::WeightInfo::assign_role(),
+				PolicyOp::RevokeRole { .. } => T::WeightInfo::revoke_role(),
+			})
+		})
+	}
+
+	/// Register a new role defined by `info` under the next available [`RoleId`].
+	fn do_create_role(info: RoleInfo<T>) -> Result<RoleId, DispatchError> {
+		let role = NextRoleId::<T>::get();
+		let next = role.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
+		Self::journal_role(role)?;
+
+		Roles::<T>::insert(role, info);
+		NextRoleId::<T>::put(next);
+		Ok(role)
+	}
+
+	/// Ensure that `permissions` and `deny_rules` are distinct and that a single role may carry
+	/// all of them.
+	fn ensure_valid_template(
+		permissions: &[Permission],
+		deny_rules: &[Permission],
+	) -> DispatchResult {
+		for rules in [permissions, deny_rules] {
+			ensure!(
+				rules.iter().collect::<BTreeSet<_>>().len() == rules.len(),
+				Error::<T>::InvalidTemplate
+			);
+		}
+		ensure!(
+			permissions.len() + deny_rules.len() <= T::MaxPermissionsPerRole::get() as usize,
+			Error::<T>::TooManyPermissions
+		);
+		Ok(())
+	}
+
+	/// Make `role` track the template of `tracking`.
+	fn start_tracking(role: RoleId, tracking: TemplateTracking) {
+		RoleTemplates::<T>::insert(role, tracking);
+		Templates::<T>::mutate(tracking.template, |template| {
+			if let Some(template) = template {
+				template.derived.saturating_inc();
+			}
+		});
+	}
+
+	/// Make `role` stop tracking its template, returning what it tracked.
+	fn stop_tracking(role: RoleId) -> Option<TemplateTracking> {
+		let tracking = RoleTemplates::<T>::take(role)?;
+		Templates::<T>::mutate(tracking.template, |template| {
+			if let Some(template) = template {
+				template.derived.saturating_dec();
+			}
+		});
+		Some(tracking)
+	}
+
+	/// Hold `amount` from `depositor` for the part `kind` of the policy of `role`.
+	///
+	/// Nothing is held without a depositor, as for `AdminOrigin`, or if a deposit is already
+	/// held for it.
+	fn hold_deposit(
+		depositor: Option<&T::AccountId>,
+		role: RoleId,
+		kind: DepositKind,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let Some(depositor) = depositor else { return Ok(()) };
+		if amount.is_zero() || Deposits::<T>::contains_key(role, kind) {
+			return Ok(())
+		}
+		T::Currency::hold(&HoldReason::PolicyDeposit.into(), depositor, amount)?;
+		Deposits::<T>::insert(role, kind, Deposit { depositor: depositor.clone(), amount });
+		Self::deposit_event(Event::DepositHeld {
+			role,
+			kind,
+			depositor: depositor.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Release the deposit held for the part `kind` of the policy of `role`, if any.
+	fn release_deposit(role: RoleId, kind: DepositKind) {
+		let Some(Deposit { depositor, amount }) = Deposits::<T>::take(role, kind) else { return };
+		let _ = T::Currency::release(
+			&HoldReason::PolicyDeposit.into(),
+			&depositor,
+			amount,
+			Precision::BestEffort,
+		);
+		Self::deposit_event(Event::DepositReleased { role, kind, depositor, amount });
+	}
+
+	/// Hold the deposit held for the part `kind` of the policy of `role`, if any, from
+	/// `depositor` instead, releasing it to the account it was held from.
+	fn move_deposit(role: RoleId, kind: DepositKind, depositor: &T::AccountId) -> DispatchResult {
+		let Some(deposit) = Deposits::<T>::get(role, kind) else { return Ok(()) };
+		if deposit.depositor == *depositor {
+			return Ok(())
+		}
+		T::Currency::hold(&HoldReason::PolicyDeposit.into(), depositor, deposit.amount)?;
+		Self::release_deposit(role, kind);
+		Deposits::<T>::insert(
+			role,
+			kind,
+			Deposit { depositor: depositor.clone(), amount: deposit.amount },
+		);
+		Self::deposit_event(Event::DepositHeld {
+			role,
+			kind,
+			depositor: depositor.clone(),
+			amount: deposit.amount,
+		});
+		Ok(())
+	}
+
+	/// Release the deposits held for `permission` of `role` and for its constraints and
+	/// condition.
+	fn release_permission_deposits(role: RoleId, permission: Permission) {
+		Self::release_deposit(role, DepositKind::Permission(permission));
+		Self::release_deposit(role, DepositKind::Constraints(permission));
+		Self::release_deposit(role, DepositKind::Condition(permission));
+	}
+
+	/// Release every deposit held for `role` and its policy.
+	fn release_deposits(role: RoleId) {
+		for kind in Deposits::<T>::iter_key_prefix(role).collect::<Vec<_>>() {
+			Self::release_deposit(role, kind);
+		}
+	}
+
+	/// Grant `permission` to `role`, holding a deposit from `depositor` if any.
+	fn do_grant_permission(
+		role: RoleId,
+		permission: Permission,
+		depositor: Option<&T::AccountId>,
+	) -> DispatchResult {
+		Self::journal_role(role)?;
+		Self::journal_permission(role, permission)?;
+		Self::hold_deposit(
+			depositor,
+			role,
+			DepositKind::Permission(permission),
+			T::PermissionDeposit::get(),
+		)?;
+
+		Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+			ensure!(
+				!RolePermissions::<T>::contains_key(role, permission),
+				Error::<T>::PermissionAlreadyGranted
+			);
+			ensure!(
+				info.permissions + info.deny_rules < T::MaxPermissionsPerRole::get(),
+				Error::<T>::TooManyPermissions
+			);
+			info.permissions += 1;
+			RolePermissions::<T>::insert(role, permission, ());
+			Ok(())
+		})
+	}
+
+	/// Revoke `permission` from `role`, along with its constraints and condition.
+	fn do_revoke_permission(role: RoleId, permission: Permission) -> DispatchResult {
+		Self::journal_role(role)?;
+		Self::journal_permission(role, permission)?;
+
+		Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+			ensure!(
+				RolePermissions::<T>::take(role, permission).is_some(),
+				Error::<T>::PermissionNotGranted
+			);
+			PermissionConstraints::<T>::remove(role, permission);
+			PermissionConditions::<T>::remove(role, permission);
+			Self::release_permission_deposits(role, permission);
+			info.permissions = info.permissions.saturating_sub(1);
+			Ok(())
+		})
+	}
+
+	/// Assign `role` to `account`, optionally until the block `expires_at`, replacing an expired
+	/// assignment of the same role.
+	fn do_assign_role(
+		account: &T::AccountId,
+		role: RoleId,
+		expires_at: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		ensure!(Roles::<T>::contains_key(role), Error::<T>::RoleNotFound);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expires_at.map_or(true, |expires_at| expires_at > now), Error::<T>::InvalidExpiry);
+		if let Some(existing) = AccountRoles::<T>::get(account, role) {
+			ensure!(!existing.is_active(&now), Error::<T>::RoleAlreadyAssigned);
+			Self::do_revoke_role(account, role);
+			Self::deposit_event(Event::RoleExpired { role, account: account.clone() });
+		}
+		let info = Roles::<T>::get(role).ok_or(Error::<T>::RoleNotFound)?;
+		ensure!(
+			info.max_members.map_or(true, |max| info.members < max),
+			Error::<T>::TooManyMembers
+		);
+		if let Some(prerequisite) = info.prerequisite {
+			Self::ensure_prerequisite_held(account, prerequisite, expires_at)?;
+		}
+		AccountRoleCount::<T>::try_mutate(account, |count| -> DispatchResult {
+			ensure!(*count < T::MaxRolesPerAccount::get(), Error::<T>::TooManyRoles);
+			*count += 1;
+			Ok(())
+		})?;
+		let mut authorized = Self::authorized_roles(account);
+		authorized.extend(Self::delegated_roles(account));
+		authorized.extend(Self::inherited_roles(role));
+		Self::ensure_separation_of_duty(&authorized, false)?;
+
+		AccountRoles::<T>::insert(account, role, RoleAssignment { expires_at });
+		RoleMembers::<T>::insert(role, account, ());
+		Roles::<T>::mutate(role, |info| {
+			if let Some(info) = info {
+				info.members.saturating_inc();
+			}
+		});
+		if let Some(expires_at) = expires_at {
+			RoleExpiries::<T>::insert(expires_at, (account, role), ());
+		}
+		Ok(())
+	}
+
+	/// Revoke `role` from `account`, unless `account` holds a role that requires it.
+	fn do_unassign_role(account: &T::AccountId, role: RoleId) -> DispatchResult {
+		ensure!(AccountRoles::<T>::contains_key(account, role), Error::<T>::RoleNotAssigned);
+		ensure!(
+			!Self::active_roles(account).any(|held| {
+				Roles::<T>::get(held).map_or(false, |info| info.prerequisite == Some(role))
+			}),
+			Error::<T>::RoleIsPrerequisite
+		);
+		Self::do_revoke_role(account, role);
+		Ok(())
+	}
+
+	/// Make `senior` inherit every permission of `junior`, checking at most `holders` holders and
+	/// delegates of `senior` or of a role inheriting it against separation of duty constraints.
+	fn do_link_roles(senior: RoleId, junior: RoleId, holders: u32) -> DispatchResult {
+		Self::journal_link(senior, junior)?;
+
+		ensure!(
+			Roles::<T>::contains_key(senior) && Roles::<T>::contains_key(junior),
+			Error::<T>::RoleNotFound
+		);
+		ensure!(!RoleJuniors::<T>::contains_key(senior, junior), Error::<T>::LinkAlreadyExists);
+		ensure!(
+			(RoleJuniors::<T>::iter_key_prefix(senior).count() as u32) <
+				T::MaxJuniorsPerRole::get(),
+			Error::<T>::TooManyJuniors
+		);
+		Self::ensure_can_link(senior, junior)?;
+
+		RoleJuniors::<T>::insert(senior, junior, ());
+		RoleSeniors::<T>::insert(junior, senior, ());
+		let newly_inherited = Self::inherited_roles(junior);
+		if newly_inherited
+			.iter()
+			.any(|role| RoleConstraints::<T>::iter_key_prefix(role).next().is_some())
+		{
+			Self::ensure_holders_respect_constraints(Self::inheriting_roles(senior), holders)?;
+		}
+		Ok(())
+	}
+
+	/// Make `senior` stop inheriting the permissions of `junior`.
+	fn do_unlink_roles(senior: RoleId, junior: RoleId) -> DispatchResult {
+		Self::journal_link(senior, junior)?;
+
+		ensure!(RoleJuniors::<T>::take(senior, junior).is_some(), Error::<T>::LinkNotFound);
+		RoleSeniors::<T>::remove(junior, senior);
+		Ok(())
+	}
+
+	/// Deny `permission` to `role`, holding a deposit from `depositor` if any.
+	fn do_add_deny_rule(
+		role: RoleId,
+		permission: Permission,
+		depositor: Option<&T::AccountId>,
+	) -> DispatchResult {
+		Self::journal_role(role)?;
+		Self::journal_deny_rule(role, permission)?;
+		Self::hold_deposit(
+			depositor,
+			role,
+			DepositKind::DenyRule(permission),
+			T::PermissionDeposit::get(),
+		)?;
+
+		Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+			ensure!(
+				!RoleDenyRules::<T>::contains_key(role, permission),
+				Error::<T>::DenyRuleAlreadyAdded
+			);
+			ensure!(
+				info.permissions + info.deny_rules < T::MaxPermissionsPerRole::get(),
+				Error::<T>::TooManyPermissions
+			);
+			info.deny_rules += 1;
+			RoleDenyRules::<T>::insert(role, permission, ());
+			Ok(())
+		})
+	}
+
+	/// Remove the deny rule `permission` from `role`.
+	fn do_remove_deny_rule(role: RoleId, permission: Permission) -> DispatchResult {
+		Self::journal_role(role)?;
+		Self::journal_deny_rule(role, permission)?;
+
+		Roles::<T>::try_mutate(role, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+			ensure!(
+				RoleDenyRules::<T>::take(role, permission).is_some(),
+				Error::<T>::DenyRuleNotFound
+			);
+			Self::release_deposit(role, DepositKind::DenyRule(permission));
+			info.deny_rules = info.deny_rules.saturating_sub(1);
+			Ok(())
+		})
+	}
+
+	/// Start a new version of the policy, for a change to be made.
+	fn new_policy_version() -> DispatchResult {
+		ensure!(!PendingRollback::<T>::exists(), Error::<T>::RollbackInProgress);
+		CurrentPolicyVersion::<T>::mutate(|version| version.saturating_inc());
+		Ok(())
+	}
+
+	/// Whether changes to the policy must be recorded, which they must while a snapshot exists.
+	fn journaling() -> bool {
+		Snapshots::<T>::decode_len().map_or(false, |len| len > 0)
+	}
+
+	/// Record the part of the policy `change` describes before it changes, if a snapshot may
+	/// need it.
+	fn journal(change: impl FnOnce() -> PolicyChange<T>) -> DispatchResult {
+		if !Self::journaling() {
+			return Ok(())
+		}
+		let version = CurrentPolicyVersion::<T>::get();
+		PolicyJournalLength::<T>::try_mutate(version, |length| -> DispatchResult {
+			ensure!(*length < MaxPolicyChanges::<T>::get(), Error::<T>::TooManyPolicyChanges);
+			PolicyJournal::<T>::insert(version, *length, change());
+			*length += 1;
+			Ok(())
+		})
+	}
+
+	/// Record the definition of `role` before it changes.
+	fn journal_role(role: RoleId) -> DispatchResult {
+		Self::journal(|| PolicyChange::Role { role, info: Roles::<T>::get(role) })
+	}
+
+	/// Record whether `role` is granted `permission`, with its constraints and condition,
+	/// before it changes.
+	fn journal_permission(role: RoleId, permission: Permission) -> DispatchResult {
+		Self::journal(|| PolicyChange::Permission {
+			role,
+			permission,
+			granted: RolePermissions::<T>::contains_key(role, permission),
+			constraints: PermissionConstraints::<T>::get(role, permission),
+			condition: PermissionConditions::<T>::get(role, permission),
+		})
+	}
+
+	/// Record whether `role` carries the deny rule `permission` before it changes.
+	fn journal_deny_rule(role: RoleId, permission: Permission) -> DispatchResult {
+		Self::journal(|| PolicyChange::DenyRule {
+			role,
+			permission,
+			added: RoleDenyRules::<T>::contains_key(role, permission),
+		})
+	}
+
+	/// Record whether `senior` inherits `junior` directly before it changes.
+	fn journal_link(senior: RoleId, junior: RoleId) -> DispatchResult {
+		Self::journal(|| PolicyChange::Link {
+			senior,
+			junior,
+			linked: RoleJuniors::<T>::contains_key(senior, junior),
+		})
+	}
+
+	/// Record the approvals required for assigning `role` before they change.
+	fn journal_grant_approval(role: RoleId) -> DispatchResult {
+		Self::journal(|| PolicyChange::GrantApproval {
+			role,
+			approval: GrantApprovals::<T>::get(role),
+		})
+	}
+
+	/// Restore the part of the policy recorded by `change`.
+	///
+	/// Returns whether the change created a role that had to be kept.
+	fn undo(change: PolicyChange<T>) -> bool {
+		match change {
+			PolicyChange::Role { role, info: Some(mut info) } => {
+				info.members = Roles::<T>::get(role).map_or(0, |current| current.members);
+				Roles::<T>::insert(role, info);
+			},
+			PolicyChange::Role { role, info: None } => {
+				if RoleMembers::<T>::iter_key_prefix(role).next().is_some() ||
+					RoleConstraints::<T>::iter_key_prefix(role).next().is_some()
+				{
+					return true
+				}
+				Roles::<T>::remove(role);
+				Self::stop_tracking(role);
+				Self::release_deposits(role);
+				let _ = Self::set_owner(role, None);
+				let _ = Self::set_pending_owner(role, None);
+				FrozenRoles::<T>::remove(role);
+				GrantApprovals::<T>::remove(role);
+			},
+			PolicyChange::Permission { role, permission, granted, constraints, condition } => {
+				if granted {
+					RolePermissions::<T>::insert(role, permission, ());
+				} else {
+					RolePermissions::<T>::remove(role, permission);
+					Self::release_deposit(role, DepositKind::Permission(permission));
+				}
+				match constraints {
+					Some(constraints) =>
+						PermissionConstraints::<T>::insert(role, permission, constraints),
+					None => {
+						PermissionConstraints::<T>::remove(role, permission);
+						Self::release_deposit(role, DepositKind::Constraints(permission));
+					},
+				}
+				match condition {
+					Some(condition) =>
+						PermissionConditions::<T>::insert(role, permission, condition),
+					None => {
+						PermissionConditions::<T>::remove(role, permission);
+						Self::release_deposit(role, DepositKind::Condition(permission));
+					},
+				}
+			},
+			PolicyChange::DenyRule { role, permission, added: true } =>
+				RoleDenyRules::<T>::insert(role, permission, ()),
+			PolicyChange::DenyRule { role, permission, added: false } => {
+				RoleDenyRules::<T>::remove(role, permission);
+				Self::release_deposit(role, DepositKind::DenyRule(permission));
+			},
+			PolicyChange::Link { senior, junior, linked: true } => {
+				RoleJuniors::<T>::insert(senior, junior, ());
+				RoleSeniors::<T>::insert(junior, senior, ());
+			},
+			PolicyChange::Link { senior, junior, linked: false } => {
+				RoleJuniors::<T>::remove(senior, junior);
+				RoleSeniors::<T>::remove(junior, senior);
+			},
+			PolicyChange::BootstrapAdmin(admin) => BootstrapAdmin::<T>::set(admin),
+			PolicyChange::GrantApproval { role, approval } =>
+				GrantApprovals::<T>::set(role, approval),
+		}
+		false
+	}
+
+	/// Make progress on the rollback in progress, or else prune the journal of versions no
+	/// snapshot needs, using no more than `limit`.
+	///
+	/// A rollback undoes whole versions, latest first, so the policy always matches one of its
+	/// past versions.
+	fn maintain_journal(limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut used = db.reads_writes(4, 2);
+		if used.any_gt(limit) {
+			return Weight::zero()
+		}
+
+		let mut version = CurrentPolicyVersion::<T>::get();
+		if let Some(mut rollback) = PendingRollback::<T>::get() {
+			let max_weight = T::WeightInfo::undo_policy_version(MaxPolicyChanges::<T>::get());
+			while version > rollback.to && !used.saturating_add(max_weight).any_gt(limit) {
+				let length = PolicyJournalLength::<T>::take(version);
+				used.saturating_accrue(T::WeightInfo::undo_policy_version(length));
+				for index in (0..length).rev() {
+					if PolicyJournal::<T>::take(version, index).map_or(false, Self::undo) {
+						rollback.kept_roles.saturating_inc();
+					}
+				}
+				rollback.changes.saturating_accrue(length);
+				version.saturating_dec();
+			}
+			CurrentPolicyVersion::<T>::put(version);
+			if version > rollback.to {
+				PendingRollback::<T>::put(rollback);
+			} else {
+				PendingRollback::<T>::kill();
+				Self::deposit_event(Event::PolicyRolledBack {
+					from: rollback.from,
+					to: rollback.to,
+					changes: rollback.changes,
+					kept_roles: rollback.kept_roles,
+				});
+			}
+		} else {
+			let needed_from = Snapshots::<T>::get().first().map_or(version, |(oldest, _)| *oldest);
+			let max_weight =
+				db.reads_writes(1, MaxPolicyChanges::<T>::get().saturating_add(1).into());
+			let pruned_to = JournalPrunedTo::<T>::get();
+			let mut pruned = pruned_to;
+			while pruned < needed_from && !used.saturating_add(max_weight).any_gt(limit) {
+				pruned.saturating_inc();
+				let length = PolicyJournalLength::<T>::take(pruned);
+				let _ = PolicyJournal::<T>::clear_prefix(pruned, length, None);
+				used.saturating_accrue(db.reads_writes(1, length.saturating_add(1).into()));
+			}
+			if pruned != pruned_to {
+				JournalPrunedTo::<T>::put(pruned);
+			}
+		}
+		used
+	}
+}
+
+impl<T: Config> frame_support::traits::OnKilledAccount<T::AccountId> for Pallet<T> {
+	/// Leave the roles owned by a reaped account to their admin roles or to `AdminOrigin`, and
+	/// withdraw the proposals to make it the owner of others.
+	fn on_killed_account(who: &T::AccountId) {
+		OwnedRoleCount::<T>::remove(who);
+		for (role, ()) in OwnedRoles::<T>::drain_prefix(who) {
+			RoleOwners::<T>::remove(role);
+			Self::deposit_event(Event::RoleOrphaned { role, owner: who.clone() });
+		}
+		for (role, ()) in PendingOwnedRoles::<T>::drain_prefix(who) {
+			PendingOwners::<T>::remove(role);
 		}
 	}
 }
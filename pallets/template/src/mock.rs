@@ -1,5 +1,10 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
@@ -13,7 +18,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		TemplateModule: pallet_template,
+		Rbac: pallet_template,
+		Balances: pallet_balances,
 	}
 );
 
@@ -34,18 +40,132 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
-	type OnKilledAccount = ();
+	type OnKilledAccount = Rbac;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<1>;
+}
+
+parameter_types! {
+	pub static RoleDeposit: u64 = 0;
+	pub static PermissionDeposit: u64 = 0;
+	pub static ConstraintDeposit: u64 = 0;
+	pub const DefaultEnforcementMode: pallet_template::EnforcementMode =
+		pallet_template::EnforcementMode::Enforcing;
+}
+
+ord_parameter_types! {
+	pub const IncidentResponder: u64 = 9;
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type IncidentOrigin = frame_system::EnsureSignedBy<IncidentResponder, u64>;
+	type DefaultEnforcementMode = DefaultEnforcementMode;
+	type ConstraintEvaluator = TestConstraintEvaluator;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type SpendOf = TransferredValue;
+	type ExemptCalls = ExemptCalls;
 	type WeightInfo = ();
+	type MaxNameLength = ConstU32<16>;
+	type MaxMetadataLength = ConstU32<32>;
+	type MaxPermissionsPerRole = ConstU32<4>;
+	type MaxConstraintsPerPermission = ConstU32<2>;
+	type MaxRolesPerAccount = ConstU32<3>;
+	type MaxHierarchyDepth = ConstU32<3>;
+	type MaxJuniorsPerRole = ConstU32<2>;
+	type MaxRolesPerConstraint = ConstU32<3>;
+	type MaxDelegationDepth = ConstU32<2>;
+	type MaxDelegationPeriod = ConstU64<100>;
+	type MaxDelegationsPerAccount = ConstU32<2>;
+	type MaxAttributeKeyLength = ConstU32<8>;
+	type MaxAttributeValueLength = ConstU32<8>;
+	type MaxAttributesPerAccount = ConstU32<2>;
+	type MaxConditionLength = ConstU32<4>;
+	type MaxSnapshots = ConstU32<2>;
+	type MaxPolicyBatchLength = ConstU32<4>;
+	type RoleDeposit = RoleDeposit;
+	type PermissionDeposit = PermissionDeposit;
+	type ConstraintDeposit = ConstraintDeposit;
+	type MaxOwnedRoles = ConstU32<8>;
+	type BreakGlassPeriod = ConstU64<10>;
+	type MaxGrantApprovals = ConstU32<3>;
+	type GrantProposalLifetime = ConstU64<10>;
+}
+
+/// Constraints on the arguments of the calls used in tests.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TestConstraint {
+	/// `remark_with_event` with a remark of at most this many bytes.
+	MaxRemarkLength(u32),
+	/// `delete_role` of this role.
+	DeleteRole(u32),
+}
+
+pub struct TestConstraintEvaluator;
+
+impl pallet_template::ConstraintEvaluator<RuntimeCall> for TestConstraintEvaluator {
+	type Constraint = TestConstraint;
+
+	fn evaluate(constraint: &TestConstraint, call: &RuntimeCall) -> bool {
+		match (constraint, call) {
+			(
+				TestConstraint::MaxRemarkLength(max),
+				RuntimeCall::System(frame_system::Call::remark_with_event { remark }),
+			) => remark.len() as u32 <= *max,
+			(
+				TestConstraint::DeleteRole(role),
+				RuntimeCall::Rbac(pallet_template::Call::delete_role { role: deleted }),
+			) => deleted == role,
+			_ => false,
+		}
+	}
+}
+
+/// Spends the value of balance transfers.
+pub struct TransferredValue;
+
+impl pallet_template::SpendOf<u64, RuntimeCall, u64> for TransferredValue {
+	fn spend_of(_: &u64, call: &RuntimeCall) -> Option<u64> {
+		match call {
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { value, .. } |
+				pallet_balances::Call::transfer_keep_alive { value, .. },
+			) => Some(*value),
+			_ => None,
+		}
+	}
+}
+
+/// Exempts `remark`, standing in for the calls of privileged pallets.
+pub struct ExemptCalls;
+
+impl Contains<RuntimeCall> for ExemptCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+	}
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,27 +1,3172 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	extension::invalid, mock::*, AccessDecision, AccountRoleCount, AccountRoles, Allowance,
+	AllowanceRefills, AllowanceWindow, AllowanceWindows, BreakGlassUse, CheckAccess, Condition,
+	ConditionOp, ConstraintRoles, Delegations, DelegationsBy, Deposit, DepositKind,
+	EnforcementMode, Error, Event, ExpiryCursor, GrantApproval, JournalPrunedTo, MaxPolicyChanges,
+	NextRoleId, OwnedRoleCount, OwnedRoles, PendingOwnedRoles, Permission, PermissionConditions,
+	PermissionConstraints, PermissionConstraintsOf, PolicyBatch, PolicyChange, PolicyJournal,
+	PolicyJournalLength, PolicyOp, RateLimit, RoleExpiries, RoleInfo, RolePermissions, RoleSeniors,
+	Roles, SodConstraint, SodKind, TemplatePermissions, TemplateTracking, UsageWindow,
+	UsageWindowExpiries, UsageWindows,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchError, GetDispatchInfo},
+	traits::{fungible::InspectHold, Get, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{
+	traits::SignedExtension, transaction_validity::TransactionValidityError, TokenError,
+};
+
+fn bounded<S: frame_support::traits::Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+	BoundedVec::try_from(bytes.to_vec()).unwrap()
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn create_role_works() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"payer"), bounded(b"x")));
+
+		assert_eq!(
+			Rbac::roles(1),
+			Some(RoleInfo {
+				name: bounded(b"payer"),
+				metadata: bounded(b"x"),
+				permissions: 0,
+				deny_rules: 0,
+				admin: None,
+				members: 0,
+				max_members: None,
+				prerequisite: None,
+				rate_limit: None,
+				allowance: None
+			})
+		);
+		assert_eq!(Rbac::next_role_id(), 2);
+		System::assert_last_event(Event::RoleCreated { role: 1, who: 1 }.into());
+	});
+}
+
+#[test]
+fn create_role_fails_when_ids_are_exhausted() {
+	new_test_ext().execute_with(|| {
+		NextRoleId::<Test>::put(u32::MAX);
+		assert_noop!(
+			Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")),
+			Error::<Test>::RoleIdOverflow
+		);
+	});
+}
+
+#[test]
+fn update_role_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+
+		assert_ok!(Rbac::update_role(RuntimeOrigin::root(), 0, bounded(b"root"), bounded(b"y")));
+
+		assert_eq!(
+			Rbac::roles(0),
+			Some(RoleInfo {
+				name: bounded(b"root"),
+				metadata: bounded(b"y"),
+				permissions: 0,
+				deny_rules: 0,
+				admin: None,
+				members: 0,
+				max_members: None,
+				prerequisite: None,
+				rate_limit: None,
+				allowance: None
+			})
+		);
+		System::assert_last_event(Event::RoleUpdated { role: 0, who: None }.into());
+		assert_noop!(
+			Rbac::update_role(RuntimeOrigin::root(), 1, bounded(b"root"), bounded(b"")),
+			Error::<Test>::RoleNotFound
+		);
+	});
+}
+
+#[test]
+fn delete_role_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 0)));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 3)));
+
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+
+		assert!(!Roles::<Test>::contains_key(0));
+		assert_eq!(RolePermissions::<Test>::iter_prefix(0).count(), 0);
+		System::assert_last_event(Event::RoleDeleted { role: 0, who: None }.into());
+		assert_noop!(Rbac::delete_role(RuntimeOrigin::root(), 0), Error::<Test>::RoleNotFound);
+	});
+}
+
+#[test]
+fn permission_is_derived_from_call_indices() {
+	new_test_ext().execute_with(|| {
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] });
+		assert_eq!(Rbac::permission_of(&remark), Some(Permission::new(0, 7)));
+
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 5 });
+		assert_eq!(Rbac::permission_of(&delete), Some(Permission::new(1, 2)));
+	});
+}
+
+#[test]
+fn grant_and_revoke_permission_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let permission = Permission::new(1, 2);
+		assert!(!Rbac::role_permits(0, &delete));
+
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, permission));
+		System::assert_last_event(
+			Event::PermissionGranted { role: 0, permission, who: None }.into(),
+		);
+		assert!(Rbac::role_permits(0, &delete));
+		assert_eq!(Rbac::roles(0).unwrap().permissions, 1);
+		assert_noop!(
+			Rbac::grant_permission(RuntimeOrigin::root(), 0, permission),
+			Error::<Test>::PermissionAlreadyGranted
+		);
+
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::root(), 0, permission));
+		System::assert_last_event(
+			Event::PermissionRevoked { role: 0, permission, who: None }.into(),
+		);
+		assert!(!Rbac::role_permits(0, &delete));
+		assert_eq!(Rbac::roles(0).unwrap().permissions, 0);
+		assert_noop!(
+			Rbac::revoke_permission(RuntimeOrigin::root(), 0, permission),
+			Error::<Test>::PermissionNotGranted
+		);
+	});
+}
+
+#[test]
+fn grant_permission_respects_limits() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 0)),
+			Error::<Test>::RoleNotFound
+		);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		for call_index in 0..4 {
+			assert_ok!(Rbac::grant_permission(
+				RuntimeOrigin::root(),
+				0,
+				Permission::new(0, call_index)
+			));
+		}
+		assert_noop!(
+			Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 4)),
+			Error::<Test>::TooManyPermissions
+		);
+	});
+}
+
+#[test]
+fn assign_and_revoke_role_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"payer"), bounded(b"")));
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None));
+		System::assert_last_event(
+			Event::RoleAssigned { role: 0, account: 2, expires_at: None, who: None }.into(),
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 1, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 3, 1, None));
+
+		assert!(Rbac::has_role(&2, 0));
+		let mut roles = Rbac::roles_of(&2);
+		roles.sort();
+		assert_eq!(roles, vec![0, 1]);
+		let mut members = Rbac::members_of(1);
+		members.sort();
+		assert_eq!(members, vec![2, 3]);
+		assert_eq!(AccountRoleCount::<Test>::get(2), 2);
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 2, 1));
+		System::assert_last_event(Event::RoleRevoked { role: 1, account: 2, who: None }.into());
+		assert_eq!(Rbac::roles_of(&2), vec![0]);
+		assert_eq!(Rbac::members_of(1), vec![3]);
+		assert_eq!(AccountRoleCount::<Test>::get(2), 1);
+	});
+}
+
+#[test]
+fn assign_role_fails_for_invalid_assignments() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None),
+			Error::<Test>::RoleNotFound
+		);
+		for _ in 0..4 {
+			assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"r"), bounded(b"")));
+		}
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None),
+			Error::<Test>::RoleAlreadyAssigned
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 1, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 2, None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 2, 3, None),
+			Error::<Test>::TooManyRoles
+		);
+		assert_noop!(
+			Rbac::revoke_role(RuntimeOrigin::root(), 3, 0),
+			Error::<Test>::RoleNotAssigned
+		);
+	});
+}
+
+#[test]
+fn assigned_roles_grant_permissions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		assert!(!Rbac::is_permitted(&2, &delete));
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None));
+		assert!(!Rbac::is_permitted(&2, &delete));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(2), 0));
+		assert!(Rbac::is_permitted(&2, &delete));
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 2, 0));
+		assert!(!Rbac::is_permitted(&2, &delete));
+		assert!(Rbac::session_of(&2).is_empty());
+	});
+}
+
+#[test]
+fn delete_role_fails_while_assigned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None));
+		assert_noop!(Rbac::delete_role(RuntimeOrigin::root(), 0), Error::<Test>::RoleInUse);
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 2, 0));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+	});
+}
+
+fn create_roles(count: u32) {
+	for _ in 0..count {
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"r"), bounded(b"")));
+	}
+}
+
+#[test]
+fn senior_roles_inherit_junior_permissions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(3);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 2, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 2, 0));
+		System::assert_last_event(Event::RolesLinked { senior: 1, junior: 2, who: None }.into());
+		assert!(Rbac::role_permits(0, &delete));
+		assert!(Rbac::role_permits(1, &delete));
+		assert_eq!(Rbac::juniors_of(0), vec![1]);
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 5, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(5), 0));
+		assert!(Rbac::is_permitted(&5, &delete));
+
+		assert_ok!(Rbac::unlink_roles(RuntimeOrigin::root(), 1, 2));
+		System::assert_last_event(Event::RolesUnlinked { senior: 1, junior: 2, who: None }.into());
+		assert!(!Rbac::role_permits(0, &delete));
+		assert!(!Rbac::is_permitted(&5, &delete));
+		assert_noop!(Rbac::unlink_roles(RuntimeOrigin::root(), 1, 2), Error::<Test>::LinkNotFound);
+	});
+}
+
+#[test]
+fn link_roles_rejects_cycles() {
+	new_test_ext().execute_with(|| {
+		create_roles(3);
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 2, 0));
+
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 0, 0, 0),
+			Error::<Test>::HierarchyCycle
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 2, 0, 0),
+			Error::<Test>::HierarchyCycle
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 0),
+			Error::<Test>::HierarchyCycle
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0),
+			Error::<Test>::LinkAlreadyExists
+		);
+		assert_noop!(Rbac::link_roles(RuntimeOrigin::root(), 0, 3, 0), Error::<Test>::RoleNotFound);
+		// A shortcut is not a cycle.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 2, 0));
+	});
+}
+
+#[test]
+fn link_roles_respects_max_depth() {
+	new_test_ext().execute_with(|| {
+		create_roles(5);
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 3, 0));
+		// 0 -> 1 -> 2 -> 3 is three links long, the maximum.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 2, 0));
+
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 3, 4, 0),
+			Error::<Test>::HierarchyTooDeep
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 4, 0, 0),
+			Error::<Test>::HierarchyTooDeep
+		);
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 4, 1, 0));
+	});
+}
+
+#[test]
+fn link_roles_limits_juniors_and_follows_diamonds() {
+	new_test_ext().execute_with(|| {
+		create_roles(5);
+		// 0 inherits 3 through both 1 and 2.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 2, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 3, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 3, 0));
+		assert_eq!(Rbac::inherited_roles(0), [0, 1, 2, 3].into());
+		assert_eq!(Rbac::inheriting_roles(3), [0, 1, 2, 3].into());
+
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 0, 4, 0),
+			Error::<Test>::TooManyJuniors
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 3, 0, 0),
+			Error::<Test>::HierarchyCycle
+		);
+		// 0 -> 1 -> 3 -> 4 is three links long, the maximum.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 3, 4, 0));
+		assert_eq!(Rbac::inherited_roles(0), [0, 1, 2, 3, 4].into());
+	});
+}
+
+#[test]
+fn delete_role_fails_while_linked() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 0));
+		assert_noop!(Rbac::delete_role(RuntimeOrigin::root(), 0), Error::<Test>::RoleInUse);
+		assert_noop!(Rbac::delete_role(RuntimeOrigin::root(), 1), Error::<Test>::RoleInUse);
+
+		assert_ok!(Rbac::unlink_roles(RuntimeOrigin::root(), 0, 1));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+	});
+}
+
+#[test]
+fn expired_assignments_are_inactive() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 2, 0, Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, Some(5)));
+		System::assert_last_event(
+			Event::RoleAssigned { role: 0, account: 2, expires_at: Some(5), who: None }.into(),
+		);
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(2), 0));
+
+		System::set_block_number(4);
+		assert!(Rbac::has_role(&2, 0));
+		assert!(Rbac::is_permitted(&2, &delete));
+
+		System::set_block_number(5);
+		assert!(!Rbac::has_role(&2, 0));
+		assert!(!Rbac::is_permitted(&2, &delete));
+		assert!(Rbac::roles_of(&2).is_empty());
+		assert!(Rbac::session_of(&2).is_empty());
+
+		// An expired assignment can be replaced before it is cleaned up.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(2), 0));
+		assert!(Rbac::is_permitted(&2, &delete));
+		assert!(!RoleExpiries::<Test>::contains_key(5, (2, 0)));
+	});
+}
+
+#[test]
+fn on_idle_removes_expired_assignments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, Some(3)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 3, 1, Some(3)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 4, 1, Some(10)));
+
+		System::set_block_number(3);
+		Rbac::on_idle(3, Weight::MAX);
+
+		assert!(!AccountRoles::<Test>::contains_key(2, 0));
+		assert!(!AccountRoles::<Test>::contains_key(3, 1));
+		assert!(AccountRoles::<Test>::contains_key(4, 1));
+		assert_eq!(Rbac::members_of(1), vec![4]);
+		assert_eq!(AccountRoleCount::<Test>::get(2), 0);
+		System::assert_has_event(Event::RoleExpired { role: 0, account: 2 }.into());
+		System::assert_has_event(Event::RoleExpired { role: 1, account: 3 }.into());
+		assert_eq!(ExpiryCursor::<Test>::get(), 4);
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, Some(2)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 3, 0, Some(2)));
+
+		System::set_block_number(2);
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let one = db.reads_writes(1, 1) + <() as crate::WeightInfo>::expire_role();
+		assert_eq!(Rbac::on_idle(2, Weight::zero()), Weight::zero());
+		assert_eq!(Rbac::on_idle(2, one), one);
+		assert_eq!(Rbac::members_of(0).len(), 1);
+		assert_eq!(ExpiryCursor::<Test>::get(), 2);
+
+		Rbac::on_idle(2, Weight::MAX);
+		assert!(Rbac::members_of(0).is_empty());
+		assert_eq!(ExpiryCursor::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn revoke_role_removes_pending_expiry() {
+	new_test_ext().execute_with(|| {
+		create_roles(1);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 0, Some(5)));
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 2, 0));
+		assert!(!RoleExpiries::<Test>::contains_key(5, (2, 0)));
+	});
+}
+
+#[test]
+fn role_management_requires_admin() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(1), 2, 1, None),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_noop!(
+			Rbac::grant_permission(RuntimeOrigin::signed(1), 1, Permission::new(0, 0)),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_noop!(
+			Rbac::set_bootstrap_admin(RuntimeOrigin::signed(1), Some(0)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(2)),
+			Error::<Test>::RoleNotFound
+		);
+	});
+}
+
+#[test]
+fn bootstrap_admin_manages_roles_without_admin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(0)));
+		System::assert_last_event(Event::BootstrapAdminChanged { role: Some(0) }.into());
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		// The admin role only counts once it is active in the session.
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(10), 2, 1, None),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::signed(10), 2, 1, None));
+		System::assert_last_event(
+			Event::RoleAssigned { role: 1, account: 2, expires_at: None, who: Some(10) }.into(),
+		);
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(2), 3, 1, None),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::signed(10), 2, 1));
+
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(10), 2, 1, None),
+			Error::<Test>::NotRoleAdmin
+		);
+	});
+}
+
+#[test]
+fn role_admin_delegates_management_to_team_leads() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 0: bootstrap admin, 1: team member, 2: team lead, 3: head of department.
+		create_roles(4);
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(0)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::signed(10), 11, 2, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(11), 2));
+
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::signed(10), 1, Some(2)));
+		System::assert_last_event(
+			Event::RoleAdminChanged { role: 1, admin: Some(2), who: Some(10) }.into(),
+		);
+		assert_eq!(Rbac::admin_of(1), Some(2));
+
+		// Only holders of the admin role manage the role from now on.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::signed(11), 12, 1, None));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::signed(11), 1, Permission::new(0, 0)));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(10), 13, 1, None),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(11), 13, 0, None),
+			Error::<Test>::NotRoleAdmin
+		);
+
+		// Holders of a role inheriting the admin role are admins as well.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 3, 2, 0));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 14, 3, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(14), 3));
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::signed(14), 12, 1));
+	});
+}
+
+fn constraint_roles(roles: &[u32]) -> ConstraintRoles<Test> {
+	BoundedVec::try_from(roles.to_vec()).unwrap()
+}
+
+#[test]
+fn sod_constraint_blocks_conflicting_assignments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 0: requester, 1: approver, 2: auditor inheriting approver.
+		create_roles(3);
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 0));
+
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Static,
+			0
+		));
+		System::assert_last_event(
+			Event::SodConstraintAdded {
+				constraint: 0,
+				roles: constraint_roles(&[0, 1]),
+				max_held: 1,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Rbac::sod_constraints(0),
+			Some(SodConstraint {
+				roles: constraint_roles(&[0, 1]),
+				max_held: 1,
+				kind: SodKind::Static
+			})
+		);
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 10, 2, None),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 2, None));
+
+		assert_ok!(Rbac::remove_sod_constraint(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::SodConstraintRemoved { constraint: 0 }.into());
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+		assert_noop!(
+			Rbac::remove_sod_constraint(RuntimeOrigin::root(), 0),
+			Error::<Test>::ConstraintNotFound
+		);
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn sod_constraint_validates_existing_assignments() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		create_roles(4);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 2, None));
+
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::signed(10),
+				constraint_roles(&[0, 1]),
+				1,
+				SodKind::Static,
+				3
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 1]),
+				1,
+				SodKind::Static,
+				1
+			),
+			Error::<Test>::HoldersWitnessTooLow
+		);
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 1]),
+				1,
+				SodKind::Static,
+				2
+			),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1, 2]),
+			2,
+			SodKind::Static,
+			3
+		));
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 2]),
+			1,
+			SodKind::Static,
+			2
+		));
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 3]),
+			1,
+			SodKind::Static,
+			1
+		));
+		// Roles covered by a constraint cannot be deleted until it is removed.
+		assert_noop!(Rbac::delete_role(RuntimeOrigin::root(), 3), Error::<Test>::RoleInUse);
+
+		for (roles, max_held) in
+			[(&[0, 0][..], 1), (&[0][..], 0), (&[0, 1][..], 0), (&[0, 1][..], 2)]
+		{
+			assert_noop!(
+				Rbac::add_sod_constraint(
+					RuntimeOrigin::root(),
+					constraint_roles(roles),
+					max_held,
+					SodKind::Static,
+					9
+				),
+				Error::<Test>::InvalidConstraint
+			);
+		}
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 4]),
+				1,
+				SodKind::Static,
+				9
+			),
+			Error::<Test>::RoleNotFound
+		);
+	});
+}
+
+#[test]
+fn link_roles_respects_sod_constraints() {
+	new_test_ext().execute_with(|| {
+		create_roles(4);
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Static,
+			0
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 2, None));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 3, 2, 0));
+
+		// Linking 2 to 1 would authorize the holder of 0 and 2 for both 0 and 1.
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 0),
+			Error::<Test>::HoldersWitnessTooLow
+		);
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 1),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		// A role must not inherit conflicting roles on its own.
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 0, 1, 1),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 10, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 1));
+		assert_eq!(Rbac::authorized_roles(&10).into_iter().collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(Rbac::inheriting_roles(1).into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn sessions_activate_held_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+		System::assert_last_event(Event::RoleActivated { role: 1, account: 10 }.into());
+		assert_eq!(Rbac::session_of(&10), vec![1]);
+		assert_noop!(
+			Rbac::activate_role(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::RoleAlreadyActive
+		);
+		assert_noop!(
+			Rbac::activate_role(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::RoleNotAssigned
+		);
+
+		assert_ok!(Rbac::deactivate_role(RuntimeOrigin::signed(10), 1));
+		System::assert_last_event(Event::RoleDeactivated { role: 1, account: 10 }.into());
+		assert!(Rbac::session_of(&10).is_empty());
+		assert_noop!(
+			Rbac::deactivate_role(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::RoleNotActive
+		);
+	});
+}
+
+#[test]
+fn dynamic_sod_constraint_limits_active_roles() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+
+		// Conflicting roles may be held but not be active together.
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 1]),
+				1,
+				SodKind::Dynamic,
+				2
+			),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::deactivate_role(RuntimeOrigin::signed(10), 1));
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Dynamic,
+			2
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 1, None));
+
+		assert_noop!(
+			Rbac::activate_role(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::deactivate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+	});
+}
+
+#[test]
+fn link_roles_respects_dynamic_sod_constraints() {
+	new_test_ext().execute_with(|| {
+		create_roles(3);
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Dynamic,
+			0
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 2, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 2));
+
+		// Linking 2 to 1 would make 0 and 1 active together in the session of 10.
+		assert_noop!(
+			Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 1),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::deactivate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 1));
+		assert_eq!(Rbac::session_authorized_roles(&10).into_iter().collect::<Vec<_>>(), vec![1, 2]);
+	});
+}
+
+fn delegated(permissions: &[Permission]) -> Option<crate::DelegatedPermissions<Test>> {
+	Some(BoundedVec::try_from(permissions.to_vec()).unwrap())
+}
+
+#[test]
+fn delegation_grants_role_for_bounded_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, Some(50)));
+
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 1, None),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 102, None),
+			Error::<Test>::InvalidExpiry
+		);
+		// A delegation cannot outlast the assignment it is derived from.
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 51, None),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(12), 11, 0, None, 10, None),
+			Error::<Test>::RoleNotAssigned
+		);
+
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 10, None));
+		System::assert_last_event(
+			Event::RoleDelegated {
+				delegation: 0,
+				role: 0,
+				delegator: 10,
+				delegate: 11,
+				expires_at: 10,
+			}
+			.into(),
+		);
+		assert!(Rbac::is_permitted(&11, &delete));
+		assert_eq!(Rbac::delegation_chain_for(&11, &delete), Some(vec![0]));
+		// Delegated roles do not make the delegate an admin.
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(0)));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(11), 12, 0, None),
+			Error::<Test>::NotRoleAdmin
+		);
+
+		System::set_block_number(10);
+		assert!(!Rbac::is_permitted(&11, &delete));
+		assert_eq!(Rbac::delegation_chain_for(&11, &delete), None);
+		Rbac::on_idle(10, Weight::MAX);
+		assert!(Delegations::<Test>::get(0).is_none());
+		System::assert_has_event(Event::DelegationExpired { delegation: 0 }.into());
+	});
+}
+
+#[test]
+fn delegation_chains_are_bounded_and_cascade() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 20, None));
+
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(12), 13, 0, None, 20, Some(0)),
+			Error::<Test>::NotDelegate
+		);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(11), 12, 0, None, 21, Some(0)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(11), 12, 0, None, 20, Some(0)));
+		assert_eq!(Rbac::delegation_chain_for(&12, &delete), Some(vec![0, 1]));
+		assert_eq!(Rbac::delegations(1).unwrap().depth, 2);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(12), 13, 0, None, 20, Some(1)),
+			Error::<Test>::DelegationTooDeep
+		);
+
+		// Revoking the assignment backing the chain invalidates every delegation in it, for good.
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 10, 0));
+		assert!(Delegations::<Test>::get(0).is_none());
+		assert!(DelegationsBy::<Test>::iter_key_prefix(10).next().is_none());
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert!(!Rbac::is_permitted(&11, &delete));
+		assert!(!Rbac::is_permitted(&12, &delete));
+		assert_eq!(Rbac::delegation_chain(1), None);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(12), 13, 0, None, 20, Some(1)),
+			Error::<Test>::DelegationNotFound
+		);
+	});
+}
+
+#[test]
+fn delegations_may_be_limited_to_permissions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let (delete_permission, remark_permission) = (Permission::new(1, 2), Permission::new(0, 7));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, delete_permission));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, remark_permission));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+
+		assert_noop!(
+			Rbac::delegate_role(
+				RuntimeOrigin::signed(10),
+				11,
+				0,
+				delegated(&[Permission::new(1, 3)]),
+				20,
+				None
+			),
+			Error::<Test>::PermissionsNotDelegable
+		);
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(10),
+			11,
+			0,
+			delegated(&[delete_permission]),
+			20,
+			None
+		));
+		assert!(Rbac::is_permitted(&11, &delete));
+		assert!(!Rbac::is_permitted(&11, &remark));
+
+		// Delegates may only pass on what was delegated to them.
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(11), 12, 0, None, 20, Some(0)),
+			Error::<Test>::PermissionsNotDelegable
+		);
+		assert_noop!(
+			Rbac::delegate_role(
+				RuntimeOrigin::signed(11),
+				12,
+				0,
+				delegated(&[remark_permission]),
+				20,
+				Some(0)
+			),
+			Error::<Test>::PermissionsNotDelegable
+		);
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(11),
+			12,
+			0,
+			delegated(&[delete_permission]),
+			20,
+			Some(0)
+		));
+		assert!(Rbac::is_permitted(&12, &delete));
+
+		// Permissions revoked from the role are no longer delegated either.
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::root(), 0, delete_permission));
+		assert!(!Rbac::is_permitted(&12, &delete));
+	});
+}
+
+#[test]
+fn revoke_delegation_cascades() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 20, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(11), 12, 0, None, 20, Some(0)));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 13, 0, None, 20, None));
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 14, 0, None, 20, None),
+			Error::<Test>::TooManyDelegations
+		);
+
+		assert_noop!(
+			Rbac::revoke_delegation(RuntimeOrigin::signed(12), 0),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::revoke_delegation(RuntimeOrigin::signed(10), 0));
+		System::assert_last_event(Event::DelegationRevoked { delegation: 0, who: Some(10) }.into());
+		assert!(!Rbac::is_permitted(&11, &delete));
+		assert!(!Rbac::is_permitted(&12, &delete));
+		assert!(Rbac::is_permitted(&13, &delete));
+
+		assert_ok!(Rbac::revoke_delegation(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::DelegationRevoked { delegation: 2, who: None }.into());
+		assert_noop!(
+			Rbac::revoke_delegation(RuntimeOrigin::root(), 2),
+			Error::<Test>::DelegationNotFound
+		);
+
+		// The orphaned delegation is removed once it expires.
+		System::set_block_number(20);
+		Rbac::on_idle(20, Weight::MAX);
+		assert!(Delegations::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn only_effective_delegations_count_against_their_delegate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		for holder in [10, 20, 30] {
+			assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), holder, 0, None));
+		}
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 20, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(11), 12, 0, None, 20, Some(0)));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(20), 12, 0, None, 20, None));
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(30), 12, 0, None, 20, None),
+			Error::<Test>::TooManyDelegations
+		);
+
+		// The orphaned delegation is kept until it expires, but no longer takes a slot.
+		assert_ok!(Rbac::revoke_delegation(RuntimeOrigin::signed(10), 0));
+		assert!(Delegations::<Test>::contains_key(1));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(30), 12, 0, None, 20, None));
+
+		// The delegate may decline a delegation.
+		assert_noop!(
+			Rbac::revoke_delegation(RuntimeOrigin::signed(13), 2),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::revoke_delegation(RuntimeOrigin::signed(12), 2));
+		System::assert_last_event(Event::DelegationRevoked { delegation: 2, who: Some(12) }.into());
+		assert!(!Delegations::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn delegation_respects_sod_constraints() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Static,
+			0
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 1, None));
+
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 20, None),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 12, 0, None, 20, None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 12, 1, None),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+	});
+}
+
+#[test]
+fn delegation_cannot_get_around_separation_of_duty() {
+	new_test_ext().execute_with(|| {
+		create_roles(5);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 7)));
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[0, 1]),
+			1,
+			SodKind::Static,
+			0
+		));
+		assert_ok!(Rbac::add_sod_constraint(
+			RuntimeOrigin::root(),
+			constraint_roles(&[2, 3]),
+			1,
+			SodKind::Dynamic,
+			0
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 1, None));
+
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 10, 0, None, 20, None),
+			Error::<Test>::SelfDelegation
+		);
+		// Delegating some permissions counts as delegating the roles granting them.
+		assert_noop!(
+			Rbac::delegate_role(
+				RuntimeOrigin::signed(10),
+				11,
+				0,
+				delegated(&[Permission::new(0, 7)]),
+				20,
+				None
+			),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+
+		// Delegated roles are in effect without being activated, so dynamic constraints apply.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 12, 2, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(12), 2));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 13, 3, None));
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(13), 12, 3, None, 20, None),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 14, 2, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(13), 14, 3, None, 20, None));
+		assert_noop!(
+			Rbac::activate_role(RuntimeOrigin::signed(14), 2),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+
+		// Delegates are checked, and counted, when a constraint is registered.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 15, 4, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 15, 0, None, 20, None));
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 4]),
+				1,
+				SodKind::Static,
+				2
+			),
+			Error::<Test>::HoldersWitnessTooLow
+		);
+		assert_noop!(
+			Rbac::add_sod_constraint(
+				RuntimeOrigin::root(),
+				constraint_roles(&[0, 4]),
+				1,
+				SodKind::Static,
+				3
+			),
+			Error::<Test>::SeparationOfDutyViolated
+		);
+	});
+}
+
+#[test]
+fn max_members_limits_assignments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_noop!(
+			Rbac::set_max_members(RuntimeOrigin::signed(10), 0, Some(1)),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::set_max_members(RuntimeOrigin::root(), 0, Some(1)));
+		System::assert_last_event(
+			Event::RoleMaxMembersChanged { role: 0, max_members: Some(1), who: None }.into(),
+		);
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 11, 0, None),
+			Error::<Test>::TooManyMembers
+		);
+		assert_noop!(
+			Rbac::set_max_members(RuntimeOrigin::root(), 0, Some(0)),
+			Error::<Test>::TooManyMembers
+		);
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 10, 0));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 0, None));
+		assert_ok!(Rbac::set_max_members(RuntimeOrigin::root(), 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+	});
+}
+
+#[test]
+fn prerequisite_roles_must_be_held() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 0: employee, 1: treasurer requiring employee.
+		create_roles(2);
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 1, Some(0), 0));
+		System::assert_last_event(
+			Event::RolePrerequisiteChanged { role: 1, prerequisite: Some(0), who: None }.into(),
+		);
+
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, Some(10)));
+		// The prerequisite must be held for at least as long.
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::root(), 10, 1, Some(11)),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, Some(10)));
+
+		assert_noop!(
+			Rbac::revoke_role(RuntimeOrigin::root(), 10, 0),
+			Error::<Test>::RoleIsPrerequisite
+		);
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 10, 1));
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 10, 0));
+	});
+}
+
+#[test]
+fn whole_role_delegations_respect_max_members_and_prerequisites() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 0: employee, 1: treasurer requiring employee.
+		create_roles(2);
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 1, Some(0), 0));
+		assert_ok!(Rbac::set_max_members(RuntimeOrigin::root(), 1, Some(2)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 1, None, 20, None),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 0, Some(15)));
+		// The prerequisite must be held for as long as the delegation lasts.
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 1, None, 20, None),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 1, None, 15, None));
+		assert_eq!(Rbac::delegates_of(1).into_iter().collect::<Vec<_>>(), vec![11]);
+
+		// Delegates count as holders.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 12, 0, None));
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(10), 12, 1, None, 20, None),
+			Error::<Test>::TooManyMembers
+		);
+		// Delegating some permissions only does not make the delegate a holder.
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 1, Permission::new(0, 7)));
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(10),
+			12,
+			1,
+			delegated(&[Permission::new(0, 7)]),
+			20,
+			None
+		));
+	});
+}
+
+#[test]
+fn set_prerequisite_validates_roles_and_holders() {
+	new_test_ext().execute_with(|| {
+		create_roles(4);
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 1, Some(1), 0),
+			Error::<Test>::InvalidPrerequisite
+		);
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 1, Some(4), 0),
+			Error::<Test>::RoleNotFound
+		);
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 1, Some(0), 0));
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 2, Some(1), 0));
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 0, Some(2), 0),
+			Error::<Test>::InvalidPrerequisite
+		);
+		// No account could hold 3 and the three roles it would require.
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 3, Some(2), 0),
+			Error::<Test>::InvalidPrerequisite
+		);
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 3, None));
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 3, Some(0), 0),
+			Error::<Test>::HoldersWitnessTooLow
+		);
+		assert_noop!(
+			Rbac::set_prerequisite(RuntimeOrigin::root(), 3, Some(0), 1),
+			Error::<Test>::PrerequisiteNotHeld
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 3, Some(0), 1));
+		assert_ok!(Rbac::set_prerequisite(RuntimeOrigin::root(), 2, None, 0));
+		assert_eq!(Rbac::roles(2).unwrap().prerequisite, None);
+	});
+}
+
+#[test]
+fn wildcard_permissions_cover_pallets_and_runtime() {
+	new_test_ext().execute_with(|| {
+		create_roles(3);
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(1)));
+		assert!(Rbac::role_permits(0, &delete));
+		assert!(!Rbac::role_permits(0, &remark));
+		assert_eq!(Rbac::permitting_permission(0, &delete), Some(Permission::pallet(1)));
+
+		// The most specific permission is reported when several cover the call.
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		assert_eq!(Rbac::permitting_permission(0, &delete), Some(Permission::new(1, 2)));
+		assert_eq!(Rbac::permissions_of(0), vec![Permission::new(1, 2), Permission::pallet(1)]);
+
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 1, Permission::All));
+		assert_eq!(Rbac::permitting_permission(1, &remark), Some(Permission::All));
+		assert_eq!(Rbac::permitting_permission(1, &delete), Some(Permission::All));
+
+		// Precedence holds across the roles inherited through the hierarchy.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 0));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 0, 0));
+		assert_eq!(Rbac::permitting_permission(2, &delete), Some(Permission::new(1, 2)));
+		assert_eq!(Rbac::permitting_permission(2, &remark), Some(Permission::All));
+
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		assert!(Rbac::is_permitted(&10, &delete));
+		assert!(!Rbac::is_permitted(&10, &remark));
+	});
+}
+
+#[test]
+fn wildcard_permissions_can_be_delegated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(10),
+			11,
+			0,
+			delegated(&[Permission::pallet(1)]),
+			20,
+			None
+		));
+		assert!(Rbac::is_permitted(&11, &delete));
+		assert!(!Rbac::is_permitted(&11, &remark));
+
+		assert_noop!(
+			Rbac::delegate_role(
+				RuntimeOrigin::signed(11),
+				12,
+				0,
+				delegated(&[Permission::All]),
+				20,
+				Some(0)
+			),
+			Error::<Test>::PermissionsNotDelegable
+		);
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(11),
+			12,
+			0,
+			delegated(&[Permission::new(1, 2)]),
+			20,
+			Some(0)
+		));
+		assert!(Rbac::is_permitted(&12, &delete));
+	});
+}
+
+#[test]
+fn deny_rules_are_managed_within_the_permission_budget() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::pallet(1)));
+		System::assert_last_event(
+			Event::DenyRuleAdded { role: 0, permission: Permission::pallet(1), who: None }.into(),
+		);
+		assert_noop!(
+			Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::pallet(1)),
+			Error::<Test>::DenyRuleAlreadyAdded
+		);
+		assert_noop!(
+			Rbac::add_deny_rule(RuntimeOrigin::signed(10), 0, Permission::All),
+			Error::<Test>::NotRoleAdmin
+		);
+		for call_index in 0..3 {
+			assert_ok!(Rbac::grant_permission(
+				RuntimeOrigin::root(),
+				0,
+				Permission::new(0, call_index)
+			));
+		}
+		assert_noop!(
+			Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::All),
+			Error::<Test>::TooManyPermissions
+		);
+		assert_eq!(Rbac::deny_rules_of(0), vec![Permission::pallet(1)]);
+
+		assert_ok!(Rbac::remove_deny_rule(RuntimeOrigin::root(), 0, Permission::pallet(1)));
+		System::assert_last_event(
+			Event::DenyRuleRemoved { role: 0, permission: Permission::pallet(1), who: None }.into(),
+		);
+		assert_noop!(
+			Rbac::remove_deny_rule(RuntimeOrigin::root(), 0, Permission::pallet(1)),
+			Error::<Test>::DenyRuleNotFound
+		);
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+		assert!(Rbac::deny_rules_of(0).is_empty());
+	});
+}
+
+#[test]
+fn deny_rules_override_inherited_and_wildcard_allows() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 0));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(1)));
+
+		// A deny rule of the senior role wins over the wildcard allow it inherits.
+		assert_eq!(
+			Rbac::role_decision(1, &delete),
+			AccessDecision::Denied { role: 1, permission: Permission::pallet(1) }
+		);
+		assert_eq!(
+			Rbac::role_decision(1, &remark),
+			AccessDecision::Allowed { role: 0, permission: Permission::All, delegation: None }
+		);
+		assert!(Rbac::role_permits(0, &delete));
+		assert!(!Rbac::role_permits(1, &delete));
+
+		// Even an exact allow loses against a wildcard deny.
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 1, Permission::new(1, 2)));
+		assert!(!Rbac::role_permits(1, &delete));
+
+		// The most specific deny rule is reported, whichever role carries it.
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		assert_eq!(
+			Rbac::role_decision(1, &delete),
+			AccessDecision::Denied { role: 0, permission: Permission::new(1, 2) }
+		);
+	});
+}
+
+#[test]
+fn access_decision_resolves_rules_of_every_role_in_scope() {
+	new_test_ext().execute_with(|| {
+		create_roles(3);
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 1, Permission::pallet(1)));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(0)));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 2, Permission::pallet(0)));
+		for role in 0..3 {
+			assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, role, None));
+		}
+		assert_eq!(Rbac::access_decision(&10, &remark), AccessDecision::NotPermitted);
+
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_eq!(
+			Rbac::access_decision(&10, &remark),
+			AccessDecision::Allowed { role: 0, permission: Permission::All, delegation: None }
+		);
+		// A deny rule of another active role wins over the allow of the first one.
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 2));
+		assert_eq!(
+			Rbac::access_decision(&10, &remark),
+			AccessDecision::Denied { role: 2, permission: Permission::pallet(0) }
+		);
+		assert!(!Rbac::is_permitted(&10, &remark));
+		// Equally specific rules resolve to the lowest role id.
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+		assert_eq!(
+			Rbac::access_decision(&10, &remark),
+			AccessDecision::Denied { role: 1, permission: Permission::pallet(0) }
+		);
+		assert_eq!(
+			Rbac::access_decision(&10, &delete),
+			AccessDecision::Allowed {
+				role: 1,
+				permission: Permission::pallet(1),
+				delegation: None
+			}
+		);
+	});
+}
+
+#[test]
+fn deny_rules_apply_to_delegated_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::new(1, 2)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 11, 0, None, 20, None));
+
+		assert_eq!(
+			Rbac::access_decision(&11, &delete),
+			AccessDecision::Denied { role: 0, permission: Permission::new(1, 2) }
+		);
+		assert_eq!(
+			Rbac::access_decision(&11, &remark),
+			AccessDecision::Allowed { role: 0, permission: Permission::All, delegation: Some(0) }
+		);
+		// Denied permissions cannot be delegated.
+		assert_noop!(
+			Rbac::delegate_role(
+				RuntimeOrigin::signed(10),
+				12,
+				0,
+				delegated(&[Permission::new(1, 2)]),
+				20,
+				None
+			),
+			Error::<Test>::PermissionsNotDelegable
+		);
+	});
+}
+
+fn constraints(constraints: &[TestConstraint]) -> PermissionConstraintsOf<Test> {
+	BoundedVec::try_from(constraints.to_vec()).unwrap()
+}
+
+#[test]
+fn set_permission_constraints_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let max_remark = constraints(&[TestConstraint::MaxRemarkLength(2)]);
+		assert_noop!(
+			Rbac::set_permission_constraints(
+				RuntimeOrigin::root(),
+				0,
+				Permission::pallet(0),
+				max_remark.clone()
+			),
+			Error::<Test>::PermissionNotGranted
+		);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_noop!(
+			Rbac::set_permission_constraints(
+				RuntimeOrigin::signed(10),
+				0,
+				Permission::pallet(0),
+				max_remark.clone()
+			),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			max_remark.clone()
+		));
+		System::assert_last_event(
+			Event::PermissionConstraintsSet {
+				role: 0,
+				permission: Permission::pallet(0),
+				constraints: max_remark.clone(),
+				who: None,
+			}
+			.into(),
+		);
+		assert_eq!(PermissionConstraints::<Test>::get(0, Permission::pallet(0)), Some(max_remark));
+
+		// Empty constraints lift the constraints.
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			constraints(&[])
+		));
+		assert!(!PermissionConstraints::<Test>::contains_key(0, Permission::pallet(0)));
+
+		// Constraints go along with the permission.
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			constraints(&[TestConstraint::MaxRemarkLength(2)])
+		));
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert!(!PermissionConstraints::<Test>::contains_key(0, Permission::pallet(0)));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			constraints(&[TestConstraint::MaxRemarkLength(2)])
+		));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+		assert!(!PermissionConstraints::<Test>::contains_key(0, Permission::pallet(0)));
+	});
+}
+
+#[test]
+fn permissions_only_allow_calls_satisfying_their_constraints() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		let remark = |len: usize| {
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![0; len] })
+		};
+		let delete = |role| RuntimeCall::Rbac(crate::Call::delete_role { role });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 7)));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::new(0, 7),
+			constraints(&[TestConstraint::MaxRemarkLength(2)])
+		));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::All,
+			constraints(&[TestConstraint::DeleteRole(1)])
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+
+		assert_eq!(
+			Rbac::access_decision(&10, &remark(2)),
+			AccessDecision::Allowed {
+				role: 0,
+				permission: Permission::new(0, 7),
+				delegation: None
+			}
+		);
+		assert_eq!(Rbac::access_decision(&10, &remark(3)), AccessDecision::NotPermitted);
+		// Less specific permissions apply when the constraints of a more specific one fail.
+		assert!(Rbac::is_permitted(&10, &delete(1)));
+		assert!(!Rbac::is_permitted(&10, &delete(0)));
+
+		// Constraints apply to the roles inheriting the permission.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 0));
+		assert!(Rbac::role_permits(1, &remark(1)));
+		assert!(!Rbac::role_permits(1, &remark(3)));
+
+		// A constrained permission can be delegated, and the constraints still apply.
+		assert_ok!(Rbac::delegate_role(
+			RuntimeOrigin::signed(10),
+			11,
+			0,
+			delegated(&[Permission::new(0, 7)]),
+			20,
+			None
+		));
+		assert!(Rbac::is_permitted(&11, &remark(2)));
+		assert!(!Rbac::is_permitted(&11, &remark(3)));
+	});
+}
+
+/// Run the `CheckAccess` extension as it runs before dispatching a transaction of `who`.
+fn pre_dispatch(who: u64, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+	CheckAccess::<Test>::new().pre_dispatch(&who, call, &call.get_dispatch_info(), 0)
+}
+
+#[test]
+fn check_access_rejects_calls_not_permitted() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		let activate = RuntimeCall::Rbac(crate::Call::activate_role { role: 0 });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::new(0, 7)));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(0)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::AccessDenied)));
+		// Calls of the pallet authorize their origin themselves.
+		assert_ok!(pre_dispatch(10, &activate));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_ok!(CheckAccess::<Test>::new().validate(
+			&10,
+			&remark,
+			&remark.get_dispatch_info(),
+			0
+		));
+
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::AccessDenied)));
+	});
+}
+
+#[test]
+fn set_rate_limit_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let limit = RateLimit { calls: 2, period: 3 };
+		assert_noop!(
+			Rbac::set_rate_limit(RuntimeOrigin::root(), 0, Some(RateLimit { calls: 0, period: 3 })),
+			Error::<Test>::InvalidRateLimit
+		);
+		assert_noop!(
+			Rbac::set_rate_limit(RuntimeOrigin::root(), 0, Some(RateLimit { calls: 2, period: 0 })),
+			Error::<Test>::InvalidRateLimit
+		);
+		assert_noop!(
+			Rbac::set_rate_limit(RuntimeOrigin::root(), 1, Some(limit)),
+			Error::<Test>::RoleNotFound
+		);
+		assert_noop!(
+			Rbac::set_rate_limit(RuntimeOrigin::signed(10), 0, Some(limit)),
+			Error::<Test>::NotRoleAdmin
+		);
+
+		assert_ok!(Rbac::set_rate_limit(RuntimeOrigin::root(), 0, Some(limit)));
+		System::assert_last_event(
+			Event::RoleRateLimitChanged { role: 0, limit: Some(limit), who: None }.into(),
+		);
+		assert_eq!(Rbac::roles(0).unwrap().rate_limit, Some(limit));
+		assert_ok!(Rbac::set_rate_limit(RuntimeOrigin::root(), 0, None));
+		assert_eq!(Rbac::roles(0).unwrap().rate_limit, None);
+	});
+}
+
+#[test]
+fn rate_limits_reject_excess_calls_per_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		let delete = RuntimeCall::Rbac(crate::Call::delete_role { role: 0 });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::set_rate_limit(
+			RuntimeOrigin::root(),
+			0,
+			Some(RateLimit { calls: 2, period: 3 })
+		));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 0));
+		for account in [10, 11] {
+			assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), account, 1, None));
+			assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(account), 1));
+		}
+
+		// The limit applies to holders of roles inheriting the permission, per account.
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::RateLimitExceeded)));
+		assert_ok!(pre_dispatch(11, &remark));
+		assert_eq!(
+			UsageWindows::<Test>::get(10, Permission::pallet(0)),
+			Some(UsageWindow { calls: 2, ends_at: 4 })
+		);
+		// Calls of the pallet are not counted.
+		assert_ok!(pre_dispatch(10, &delete));
+
+		// A new window starts with the first call after the current one ended.
+		System::set_block_number(4);
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_eq!(
+			UsageWindows::<Test>::get(10, Permission::pallet(0)),
+			Some(UsageWindow { calls: 1, ends_at: 7 })
+		);
+		assert!(!UsageWindowExpiries::<Test>::contains_key(4, (10, Permission::pallet(0))));
+
+		// Ended windows are pruned in `on_idle`.
+		Rbac::on_idle(4, Weight::MAX);
+		assert!(!UsageWindows::<Test>::contains_key(11, Permission::pallet(0)));
+		assert!(UsageWindows::<Test>::contains_key(10, Permission::pallet(0)));
+		Rbac::on_idle(7, Weight::MAX);
+		assert!(!UsageWindows::<Test>::contains_key(10, Permission::pallet(0)));
+		assert_eq!(UsageWindowExpiries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn set_allowance_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let allowance = Allowance { amount: 100, period: 10 };
+		assert_noop!(
+			Rbac::set_allowance(
+				RuntimeOrigin::root(),
+				0,
+				Some(Allowance { amount: 100, period: 0 })
+			),
+			Error::<Test>::InvalidAllowance
+		);
+		assert_noop!(
+			Rbac::set_allowance(RuntimeOrigin::root(), 1, Some(allowance)),
+			Error::<Test>::RoleNotFound
+		);
+		assert_noop!(
+			Rbac::set_allowance(RuntimeOrigin::signed(10), 0, Some(allowance)),
+			Error::<Test>::NotRoleAdmin
+		);
+
+		assert_ok!(Rbac::set_allowance(RuntimeOrigin::root(), 0, Some(allowance)));
+		System::assert_last_event(
+			Event::RoleAllowanceChanged { role: 0, allowance: Some(allowance), who: None }.into(),
+		);
+		assert_eq!(Rbac::remaining_allowance(&10, 0), Some(100));
+		assert_ok!(Rbac::set_allowance(RuntimeOrigin::root(), 0, None));
+		assert_eq!(Rbac::remaining_allowance(&10, 0), None);
+	});
+}
+
+#[test]
+fn allowances_limit_transfers_per_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let transfer = |value| {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 11, value })
+		};
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::set_allowance(
+			RuntimeOrigin::root(),
+			0,
+			Some(Allowance { amount: 100, period: 10 })
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+
+		assert_ok!(pre_dispatch(10, &transfer(60)));
+		System::assert_last_event(
+			Event::AllowanceSpent { account: 10, role: 0, amount: 60, remaining: 40 }.into(),
+		);
+		assert_eq!(
+			AllowanceWindows::<Test>::get(10, 0),
+			Some(AllowanceWindow { remaining: 40, refills_at: 11 })
+		);
+		assert_eq!(pre_dispatch(10, &transfer(50)), Err(invalid(Error::<Test>::AllowanceExceeded)));
+		// Calls spending nothing are not charged.
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_ok!(pre_dispatch(10, &transfer(40)));
+		assert_eq!(Rbac::remaining_allowance(&10, 0), Some(0));
+		assert_eq!(pre_dispatch(10, &transfer(1)), Err(invalid(Error::<Test>::AllowanceExceeded)));
+
+		// The allowance refills once the period ends.
+		System::set_block_number(11);
+		assert_eq!(Rbac::remaining_allowance(&10, 0), Some(100));
+		Rbac::on_idle(11, Weight::MAX);
+		assert!(!AllowanceWindows::<Test>::contains_key(10, 0));
+		assert_eq!(AllowanceRefills::<Test>::iter().count(), 0);
+		assert_ok!(pre_dispatch(10, &transfer(100)));
+		assert_eq!(
+			AllowanceWindows::<Test>::get(10, 0),
+			Some(AllowanceWindow { remaining: 0, refills_at: 21 })
+		);
+	});
+}
+
+#[test]
+fn set_attribute_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let ops = Some(bounded(b"ops"));
+		assert_noop!(
+			Rbac::set_attribute(RuntimeOrigin::signed(10), 10, bounded(b"dept"), ops.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Rbac::set_attribute(RuntimeOrigin::root(), 10, bounded(b"dept"), ops.clone()));
+		System::assert_last_event(
+			Event::AttributeSet { account: 10, key: bounded(b"dept"), value: ops.clone() }.into(),
+		);
+		assert_ok!(Rbac::set_attribute(RuntimeOrigin::root(), 10, bounded(b"site"), ops.clone()));
+		// Setting an attribute again replaces its value.
+		assert_ok!(Rbac::set_attribute(
+			RuntimeOrigin::root(),
+			10,
+			bounded(b"dept"),
+			Some(bounded(b"finance"))
+		));
+		assert_eq!(
+			Rbac::attributes(10).into_inner(),
+			vec![(bounded(b"site"), bounded(b"ops")), (bounded(b"dept"), bounded(b"finance"))]
+		);
+		assert_noop!(
+			Rbac::set_attribute(RuntimeOrigin::root(), 10, bounded(b"team"), ops),
+			Error::<Test>::TooManyAttributes
+		);
+
+		assert_ok!(Rbac::set_attribute(RuntimeOrigin::root(), 10, bounded(b"dept"), None));
+		assert_ok!(Rbac::set_attribute(RuntimeOrigin::root(), 10, bounded(b"site"), None));
+		assert!(!crate::AccountAttributes::<Test>::contains_key(10));
+	});
+}
+
+fn condition(ops: Vec<ConditionOp<Test>>) -> Condition<Test> {
+	Condition(BoundedVec::try_from(ops).unwrap())
+}
+
+#[test]
+fn conditions_gate_permissions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		let in_ops_during_window = condition(vec![
+			ConditionOp::BlockRange { from: 5, to: 10 },
+			ConditionOp::AttributeEquals { key: bounded(b"dept"), value: bounded(b"ops") },
+			ConditionOp::And,
+		]);
+		assert_noop!(
+			Rbac::set_permission_condition(
+				RuntimeOrigin::root(),
+				0,
+				Permission::pallet(0),
+				Some(in_ops_during_window.clone())
+			),
+			Error::<Test>::PermissionNotGranted
+		);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_noop!(
+			Rbac::set_permission_condition(
+				RuntimeOrigin::root(),
+				0,
+				Permission::pallet(0),
+				Some(condition(vec![ConditionOp::FreeBalanceAbove(1), ConditionOp::And]))
+			),
+			Error::<Test>::InvalidCondition
+		);
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			Some(in_ops_during_window.clone())
+		));
+		System::assert_last_event(
+			Event::PermissionConditionSet {
+				role: 0,
+				permission: Permission::pallet(0),
+				condition: Some(in_ops_during_window),
+				who: None,
+			}
+			.into(),
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+
+		assert!(!Rbac::is_permitted(&10, &remark));
+		System::set_block_number(5);
+		assert!(!Rbac::is_permitted(&10, &remark));
+		assert_ok!(Rbac::set_attribute(
+			RuntimeOrigin::root(),
+			10,
+			bounded(b"dept"),
+			Some(bounded(b"ops"))
+		));
+		assert!(Rbac::is_permitted(&10, &remark));
+		// Conditions on the caller do not hold without a caller.
+		assert!(!Rbac::role_permits(0, &remark));
+		System::set_block_number(10);
+		assert!(!Rbac::is_permitted(&10, &remark));
+
+		// Balance conditions compare the free balance of the caller.
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			Some(condition(vec![ConditionOp::FreeBalanceAbove(50)]))
+		));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 10, 50));
+		assert!(!Rbac::is_permitted(&10, &remark));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 10, 51));
+		assert!(Rbac::is_permitted(&10, &remark));
+
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			None
+		));
+		assert!(Rbac::role_permits(0, &remark));
+	});
+}
+
+#[test]
+fn check_access_charges_condition_evaluation() {
+	new_test_ext().execute_with(|| {
+		create_roles(1);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			Some(condition(vec![
+				ConditionOp::BlockRange { from: 0, to: 10 },
+				ConditionOp::Not,
+				ConditionOp::Not,
+			]))
+		));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 0));
+
+		let before = System::block_weight().total();
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_eq!(
+			System::block_weight().total(),
+			before +
+				<() as crate::WeightInfo>::check_access() +
+				<() as crate::WeightInfo>::evaluate_condition(3)
+		);
+	});
+}
+
+#[test]
+fn take_and_remove_snapshot_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_eq!(Rbac::policy_version(), 2);
+		// Assignments are not part of the policy.
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_eq!(Rbac::policy_version(), 2);
+
+		assert_noop!(
+			Rbac::take_snapshot(RuntimeOrigin::signed(1), bounded(b"v2")),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v2")));
+		System::assert_last_event(
+			Event::PolicySnapshotTaken { version: 2, name: bounded(b"v2") }.into(),
+		);
+		assert_noop!(
+			Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"again")),
+			Error::<Test>::SnapshotAlreadyTaken
+		);
+		create_roles(1);
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v3")));
+		create_roles(1);
+		assert_noop!(
+			Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v4")),
+			Error::<Test>::TooManySnapshots
+		);
+		assert_eq!(Rbac::snapshots().into_inner(), vec![(2, bounded(b"v2")), (3, bounded(b"v3"))]);
+
+		assert_noop!(
+			Rbac::remove_snapshot(RuntimeOrigin::root(), 4),
+			Error::<Test>::SnapshotNotFound
+		);
+		assert_ok!(Rbac::remove_snapshot(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::PolicySnapshotRemoved { version: 2 }.into());
+		assert_eq!(Rbac::snapshots().into_inner(), vec![(3, bounded(b"v3"))]);
+	});
+}
+
+#[test]
+fn policy_changes_are_journaled_while_a_snapshot_needs_them() {
+	new_test_ext().execute_with(|| {
+		create_roles(1);
+		assert_eq!(PolicyJournalLength::<Test>::get(1), 0);
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v1")));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_eq!(PolicyJournalLength::<Test>::get(2), 2);
+		assert_eq!(
+			PolicyJournal::<Test>::get(2, 1),
+			Some(PolicyChange::Permission {
+				role: 0,
+				permission: Permission::pallet(0),
+				granted: false,
+				constraints: None,
+				condition: None,
+			})
+		);
+
+		Rbac::on_idle(0, Weight::MAX);
+		assert_eq!(PolicyJournalLength::<Test>::get(2), 2);
+
+		// The journal is pruned once no snapshot needs it.
+		assert_ok!(Rbac::remove_snapshot(RuntimeOrigin::root(), 1));
+		Rbac::on_idle(0, Weight::MAX);
+		assert_eq!(PolicyJournalLength::<Test>::get(2), 0);
+		assert_eq!(PolicyJournal::<Test>::iter().count(), 0);
+		assert_eq!(JournalPrunedTo::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn rollback_to_restores_the_snapshot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(3);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			constraints(&[TestConstraint::MaxRemarkLength(4)])
+		));
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			Some(condition(vec![ConditionOp::BlockRange { from: 0, to: 10 }]))
+		));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(2)));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 2, 1, 0));
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(0)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		let version = Rbac::policy_version();
+		let role_0 = Rbac::roles(0);
+		let role_1 = Rbac::roles(1);
+		let role_2 = Rbac::roles(2);
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"stable")));
+
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 1, Permission::All));
+		assert_ok!(Rbac::remove_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(2)));
+		assert_ok!(Rbac::unlink_roles(RuntimeOrigin::root(), 2, 1));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 2));
+		assert_ok!(Rbac::update_role(RuntimeOrigin::root(), 0, bounded(b"x"), bounded(b"")));
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), None));
+		create_roles(2);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 4, None));
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"broken")));
+		let from = Rbac::policy_version();
+
+		assert_noop!(
+			Rbac::rollback_to(RuntimeOrigin::signed(1), version),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Rbac::rollback_to(RuntimeOrigin::root(), version + 1),
+			Error::<Test>::SnapshotNotFound
+		);
+		assert_ok!(Rbac::rollback_to(RuntimeOrigin::root(), version));
+		System::assert_last_event(Event::PolicyRollbackStarted { from, to: version }.into());
+		assert_eq!(Rbac::snapshots().into_inner(), vec![(version, bounded(b"stable"))]);
+		assert_noop!(
+			Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All),
+			Error::<Test>::RollbackInProgress
+		);
+
+		Rbac::on_idle(1, Weight::MAX);
+
+		System::assert_last_event(
+			Event::PolicyRolledBack { from, to: version, changes: 12, kept_roles: 1 }.into(),
+		);
+		assert_eq!(Rbac::pending_rollback(), None);
+		assert_eq!(Rbac::policy_version(), version);
+		assert_eq!((Rbac::roles(0), Rbac::roles(1), Rbac::roles(2)), (role_0, role_1, role_2));
+		assert_eq!(Rbac::permissions_of(0), vec![Permission::pallet(0)]);
+		assert_eq!(
+			PermissionConstraints::<Test>::get(0, Permission::pallet(0)),
+			Some(constraints(&[TestConstraint::MaxRemarkLength(4)]))
+		);
+		assert!(PermissionConditions::<Test>::contains_key(0, Permission::pallet(0)));
+		assert!(Rbac::permissions_of(1).is_empty());
+		assert_eq!(Rbac::deny_rules_of(1), vec![Permission::pallet(2)]);
+		assert_eq!(Rbac::juniors_of(2), vec![1]);
+		assert!(RoleSeniors::<Test>::contains_key(1, 2));
+		assert_eq!(Rbac::bootstrap_admin(), Some(0));
+		// Roles created since the snapshot are kept while assigned.
+		assert!(Rbac::roles(3).is_none());
+		assert_eq!(Rbac::roles(4).map(|info| info.members), Some(1));
+
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+	});
+}
+
+#[test]
+fn rollback_undoes_whole_versions_across_blocks() {
+	new_test_ext().execute_with(|| {
+		create_roles(1);
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v1")));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(1)));
+		assert_ok!(Rbac::rollback_to(RuntimeOrigin::root(), 1));
+		ExpiryCursor::<Test>::put(1);
+
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let one_version = db.reads_writes(1, 1) +
+			db.reads_writes(4, 2) +
+			<() as crate::WeightInfo>::undo_policy_version(MaxPolicyChanges::<Test>::get());
+		Rbac::on_idle(0, one_version);
+		assert_eq!(Rbac::policy_version(), 2);
+		assert_eq!(Rbac::permissions_of(0), vec![Permission::pallet(0)]);
+		assert_eq!(Rbac::roles(0).unwrap().permissions, 1);
+		assert!(Rbac::pending_rollback().is_some());
+
+		Rbac::on_idle(0, one_version);
+		assert_eq!(Rbac::policy_version(), 1);
+		assert!(Rbac::permissions_of(0).is_empty());
+		assert_eq!(Rbac::roles(0).unwrap().permissions, 0);
+		assert!(Rbac::pending_rollback().is_none());
+	});
+}
+
+fn batch(ops: Vec<PolicyOp<Test>>) -> PolicyBatch<Test> {
+	BoundedVec::try_from(ops).unwrap()
+}
+
+#[test]
+fn apply_policy_batch_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		let ops = batch(vec![
+			PolicyOp::GrantPermission { role: 0, permission: Permission::pallet(0) },
+			PolicyOp::AddDenyRule { role: 1, permission: Permission::pallet(2) },
+			PolicyOp::LinkRoles { senior: 1, junior: 0, holders: 0 },
+			PolicyOp::AssignRole { account: 10, role: 1, expires_at: None },
+		]);
+		let events = System::events().len();
+
+		assert_ok!(Rbac::apply_policy_batch(RuntimeOrigin::root(), ops.clone()));
+
+		System::assert_last_event(Event::PolicyBatchApplied { batch: ops, who: None }.into());
+		assert_eq!(System::events().len(), events + 1);
+		assert_eq!(Rbac::permissions_of(0), vec![Permission::pallet(0)]);
+		assert_eq!(Rbac::deny_rules_of(1), vec![Permission::pallet(2)]);
+		assert_eq!(Rbac::juniors_of(1), vec![0]);
+		assert_eq!(Rbac::roles_of(&10), vec![1]);
+		// The policy changes of a batch make up a single version.
+		assert_eq!(Rbac::policy_version(), 3);
+	});
+}
+
+#[test]
+fn apply_policy_batch_applies_all_operations_or_none() {
+	new_test_ext().execute_with(|| {
+		create_roles(2);
+		assert_noop!(
+			Rbac::apply_policy_batch(RuntimeOrigin::root(), batch(vec![])),
+			Error::<Test>::EmptyPolicyBatch
+		);
+		assert_noop!(
+			Rbac::apply_policy_batch(
+				RuntimeOrigin::root(),
+				batch(vec![
+					PolicyOp::GrantPermission { role: 0, permission: Permission::pallet(0) },
+					PolicyOp::AssignRole { account: 10, role: 0, expires_at: None },
+					PolicyOp::GrantPermission { role: 0, permission: Permission::pallet(0) },
+				])
+			),
+			Error::<Test>::PermissionAlreadyGranted
+		);
+
+		// Every operation must be authorized.
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 5, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(5), 1));
+		let grant = PolicyOp::GrantPermission { role: 0, permission: Permission::pallet(0) };
+		assert_noop!(
+			Rbac::apply_policy_batch(
+				RuntimeOrigin::signed(5),
+				batch(vec![
+					grant.clone(),
+					PolicyOp::GrantPermission { role: 1, permission: Permission::pallet(0) },
+				])
+			),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::apply_policy_batch(RuntimeOrigin::signed(5), batch(vec![grant])));
+		assert_eq!(Rbac::permissions_of(0), vec![Permission::pallet(0)]);
+	});
+}
+
+#[test]
+fn apply_policy_batch_weight_depends_on_its_operations() {
+	let ops = batch(vec![
+		PolicyOp::GrantPermission { role: 0, permission: Permission::pallet(0) },
+		PolicyOp::AssignRole { account: 10, role: 0, expires_at: None },
+		PolicyOp::AssignRole { account: 11, role: 0, expires_at: None },
+	]);
+	let call = crate::Call::<Test>::apply_policy_batch { batch: ops };
+	assert_eq!(
+		call.get_dispatch_info().weight,
+		<() as crate::WeightInfo>::grant_permission() +
+			<() as crate::WeightInfo>::assign_role() +
+			<() as crate::WeightInfo>::assign_role()
+	);
+}
+
+fn permissions(permissions: &[Permission]) -> TemplatePermissions<Test> {
+	BoundedVec::try_from(permissions.to_vec()).unwrap()
+}
+
+#[test]
+fn role_templates_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let read = Permission::new(1, 0);
+		let write = Permission::new(1, 1);
+		assert_noop!(
+			Rbac::create_template(
+				RuntimeOrigin::signed(1),
+				bounded(b"project"),
+				permissions(&[read]),
+				permissions(&[])
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Rbac::create_template(
+				RuntimeOrigin::root(),
+				bounded(b"project"),
+				permissions(&[read, read]),
+				permissions(&[])
+			),
+			Error::<Test>::InvalidTemplate
+		);
+		assert_noop!(
+			Rbac::create_template(
+				RuntimeOrigin::root(),
+				bounded(b"project"),
+				permissions(&[read, write, Permission::pallet(0)]),
+				permissions(&[Permission::pallet(2), Permission::All])
+			),
+			Error::<Test>::TooManyPermissions
+		);
+
+		assert_ok!(Rbac::create_template(
+			RuntimeOrigin::root(),
+			bounded(b"project"),
+			permissions(&[read, write]),
+			permissions(&[Permission::pallet(2)])
+		));
+		System::assert_last_event(Event::TemplateCreated { template: 0 }.into());
+		assert_ok!(Rbac::instantiate_template(
+			RuntimeOrigin::signed(1),
+			0,
+			bounded(b"alpha"),
+			bounded(b""),
+			true
+		));
+		System::assert_last_event(
+			Event::RoleInstantiated { role: 0, template: 0, tracked: true, who: 1 }.into(),
+		);
+		assert_ok!(Rbac::instantiate_template(
+			RuntimeOrigin::signed(1),
+			0,
+			bounded(b"beta"),
+			bounded(b""),
+			false
+		));
+		for role in [0, 1] {
+			assert_eq!(Rbac::permissions_of(role), vec![read, write]);
+			assert_eq!(Rbac::deny_rules_of(role), vec![Permission::pallet(2)]);
+			assert_eq!(
+				Rbac::roles(role).map(|info| (info.permissions, info.deny_rules)),
+				Some((2, 1))
+			);
+		}
+		assert_eq!(Rbac::template_of(0), Some(TemplateTracking { template: 0, revision: 0 }));
+		assert_eq!(Rbac::template_of(1), None);
+		assert_eq!(Rbac::templates(0).map(|template| template.derived), Some(1));
+		assert_noop!(Rbac::remove_template(RuntimeOrigin::root(), 0), Error::<Test>::TemplateInUse);
+
+		// Updates reach tracking roles once they are synced.
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			read,
+			constraints(&[TestConstraint::MaxRemarkLength(4)])
+		));
+		assert_ok!(Rbac::update_template(
+			RuntimeOrigin::root(),
+			0,
+			bounded(b"project"),
+			permissions(&[read, Permission::pallet(3)]),
+			permissions(&[])
+		));
+		System::assert_last_event(Event::TemplateUpdated { template: 0, revision: 1 }.into());
+		assert_eq!(Rbac::permissions_of(0), vec![read, write]);
+		assert_noop!(Rbac::sync_role(RuntimeOrigin::root(), 1), Error::<Test>::RoleNotTracking);
+		assert_ok!(Rbac::sync_role(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::RoleSynced { role: 0, template: 0, revision: 1, who: None }.into(),
+		);
+		assert_eq!(Rbac::permissions_of(0), vec![read, Permission::pallet(3)]);
+		assert!(Rbac::deny_rules_of(0).is_empty());
+		assert_eq!(Rbac::roles(0).map(|info| (info.permissions, info.deny_rules)), Some((2, 0)));
+		assert_eq!(
+			PermissionConstraints::<Test>::get(0, read),
+			Some(constraints(&[TestConstraint::MaxRemarkLength(4)]))
+		);
+		assert_eq!(Rbac::template_of(0), Some(TemplateTracking { template: 0, revision: 1 }));
+		assert_eq!(Rbac::permissions_of(1), vec![read, write]);
+
+		assert_ok!(Rbac::untrack_template(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::RoleUntracked { role: 0, template: 0, who: None }.into());
+		assert_ok!(Rbac::remove_template(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::TemplateRemoved { template: 0 }.into());
+		assert_noop!(
+			Rbac::instantiate_template(
+				RuntimeOrigin::signed(1),
+				0,
+				bounded(b"gamma"),
+				bounded(b""),
+				false
+			),
+			Error::<Test>::TemplateNotFound
+		);
+	});
+}
+
+#[test]
+fn clone_role_copies_permissions_and_constraints() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			constraints(&[TestConstraint::MaxRemarkLength(4)])
+		));
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::root(),
+			0,
+			Permission::pallet(0),
+			Some(condition(vec![ConditionOp::BlockRange { from: 0, to: 10 }]))
+		));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 0, Permission::pallet(2)));
+		assert_ok!(Rbac::set_max_members(RuntimeOrigin::root(), 0, Some(3)));
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 0, None));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 5, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(5), 1));
+
+		assert_noop!(
+			Rbac::clone_role(RuntimeOrigin::signed(10), 0, bounded(b"copy"), bounded(b"")),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_noop!(
+			Rbac::clone_role(RuntimeOrigin::root(), 7, bounded(b"copy"), bounded(b"")),
+			Error::<Test>::RoleNotFound
+		);
+		assert_ok!(Rbac::clone_role(RuntimeOrigin::signed(5), 0, bounded(b"copy"), bounded(b"")));
+
+		System::assert_last_event(Event::RoleCloned { role: 2, source: 0, who: Some(5) }.into());
+		assert_eq!(
+			Rbac::roles(2),
+			Some(RoleInfo {
+				name: bounded(b"copy"),
+				metadata: bounded(b""),
+				members: 0,
+				..Rbac::roles(0).unwrap()
+			})
+		);
+		assert_eq!(Rbac::permissions_of(2), vec![Permission::pallet(0)]);
+		assert_eq!(Rbac::deny_rules_of(2), vec![Permission::pallet(2)]);
+		assert_eq!(
+			PermissionConstraints::<Test>::get(2, Permission::pallet(0)),
+			PermissionConstraints::<Test>::get(0, Permission::pallet(0))
+		);
+		assert!(PermissionConditions::<Test>::contains_key(2, Permission::pallet(0)));
+		assert!(Rbac::members_of(2).is_empty());
+	});
+}
+
+#[test]
+fn removing_a_tracking_role_untracks_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Rbac::create_template(
+			RuntimeOrigin::root(),
+			bounded(b"project"),
+			permissions(&[Permission::pallet(0)]),
+			permissions(&[])
+		));
+		assert_ok!(Rbac::instantiate_template(
+			RuntimeOrigin::signed(1),
+			0,
+			bounded(b"alpha"),
+			bounded(b""),
+			true
+		));
+		assert_ok!(Rbac::clone_role(RuntimeOrigin::root(), 0, bounded(b"beta"), bounded(b"")));
+		assert_eq!(Rbac::template_of(1), Some(TemplateTracking { template: 0, revision: 0 }));
+		assert_eq!(Rbac::templates(0).map(|template| template.derived), Some(2));
+
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+		assert_eq!(Rbac::template_of(0), None);
+		assert_eq!(Rbac::templates(0).map(|template| template.derived), Some(1));
+
+		// Rolling back the creation of a role also untracks it.
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v1")));
+		let version = Rbac::policy_version();
+		assert_ok!(Rbac::instantiate_template(
+			RuntimeOrigin::signed(1),
+			0,
+			bounded(b"gamma"),
+			bounded(b""),
+			true
+		));
+		assert_eq!(Rbac::templates(0).map(|template| template.derived), Some(2));
+		assert_ok!(Rbac::rollback_to(RuntimeOrigin::root(), version));
+		Rbac::on_idle(1, Weight::MAX);
+
+		assert_eq!(Rbac::roles(2), None);
+		assert!(Rbac::permissions_of(2).is_empty());
+		assert_eq!(Rbac::template_of(2), None);
+		assert_eq!(Rbac::templates(0).map(|template| template.derived), Some(1));
+	});
+}
+
+fn set_deposits(role: u64, permission: u64, constraint: u64) {
+	RoleDeposit::set(role);
+	PermissionDeposit::set(permission);
+	ConstraintDeposit::set(constraint);
+}
+
+#[test]
+fn deposits_are_held_and_released() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_deposits(10, 2, 5);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 5));
+		assert_noop!(
+			Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")),
+			TokenError::FundsUnavailable
+		);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"admin"), bounded(b"")));
+		assert_eq!(
+			Rbac::deposit_of(0, DepositKind::Role),
+			Some(Deposit { depositor: 1, amount: 10 })
+		);
+		System::assert_has_event(
+			Event::DepositHeld { role: 0, kind: DepositKind::Role, depositor: 1, amount: 10 }
+				.into(),
+		);
+		assert_ok!(Rbac::set_bootstrap_admin(RuntimeOrigin::root(), Some(0)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 1, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(1), 0));
+		let permission = Permission::pallet(0);
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::signed(1), 0, permission));
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::signed(1),
+			0,
+			permission,
+			constraints(&[TestConstraint::MaxRemarkLength(4)])
+		));
+		assert_ok!(Rbac::set_permission_condition(
+			RuntimeOrigin::signed(1),
+			0,
+			permission,
+			Some(condition(vec![ConditionOp::BlockRange { from: 0, to: 10 }]))
+		));
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::signed(1), 0, Permission::pallet(2)));
+		assert_eq!(Balances::total_balance_on_hold(&1), 24);
+		// Nothing is held for changes made by the admin origin.
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(1)));
+		assert_eq!(Balances::total_balance_on_hold(&1), 24);
+
+		assert_ok!(Rbac::set_permission_constraints(
+			RuntimeOrigin::signed(1),
+			0,
+			permission,
+			constraints(&[])
+		));
+		System::assert_last_event(
+			Event::PermissionConstraintsSet {
+				role: 0,
+				permission,
+				constraints: constraints(&[]),
+				who: Some(1),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::DepositReleased {
+				role: 0,
+				kind: DepositKind::Constraints(permission),
+				depositor: 1,
+				amount: 5,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::total_balance_on_hold(&1), 19);
+		assert_ok!(Rbac::revoke_permission(RuntimeOrigin::signed(1), 0, permission));
+		assert_eq!(Balances::total_balance_on_hold(&1), 12);
+
+		assert_ok!(Rbac::revoke_role(RuntimeOrigin::root(), 1, 0));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Rbac::deposit_of(0, DepositKind::DenyRule(Permission::pallet(2))), None);
+	});
+}
+
+#[test]
+fn rolling_back_the_creation_of_a_role_releases_its_deposits() {
+	new_test_ext().execute_with(|| {
+		set_deposits(10, 2, 5);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(Rbac::create_template(
+			RuntimeOrigin::root(),
+			bounded(b"project"),
+			permissions(&[Permission::pallet(0)]),
+			permissions(&[Permission::pallet(1)])
+		));
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v0")));
+		let version = Rbac::policy_version();
+
+		assert_ok!(Rbac::instantiate_template(
+			RuntimeOrigin::signed(1),
+			0,
+			bounded(b"alpha"),
+			bounded(b""),
+			false
+		));
+		assert_eq!(Balances::total_balance_on_hold(&1), 14);
+		assert_ok!(Rbac::rollback_to(RuntimeOrigin::root(), version));
+		Rbac::on_idle(1, Weight::MAX);
+
+		assert_eq!(Rbac::roles(0), None);
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+	});
+}
+
+#[test]
+fn rollback_restores_the_approvals_required_for_roles() {
+	new_test_ext().execute_with(|| {
+		create_roles(3);
+		let approval = GrantApproval { approver: 2, threshold: 1 };
+		assert_ok!(Rbac::set_grant_approval(RuntimeOrigin::root(), 0, Some(approval)));
+		assert_ok!(Rbac::take_snapshot(RuntimeOrigin::root(), bounded(b"v0")));
+		let version = Rbac::policy_version();
+
+		assert_ok!(Rbac::set_grant_approval(RuntimeOrigin::root(), 1, Some(approval)));
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::root(), 0));
+		assert_eq!(Rbac::grant_approval_of(0), None);
+		assert_ok!(Rbac::rollback_to(RuntimeOrigin::root(), version));
+		assert_noop!(
+			Rbac::set_grant_approval(RuntimeOrigin::root(), 1, None),
+			Error::<Test>::RollbackInProgress
+		);
+		Rbac::on_idle(1, Weight::MAX);
+
+		// The restored role still requires approvals for its assignments.
+		assert!(Rbac::roles(0).is_some());
+		assert_eq!(Rbac::grant_approval_of(0), Some(approval));
+		assert_eq!(Rbac::grant_approval_of(1), None);
+	});
+}
+
+#[test]
+fn role_ownership_is_transferred_in_two_steps() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(1);
+		assert_eq!(Rbac::owner_of(0), Some(1));
+
+		assert_noop!(
+			Rbac::propose_owner(RuntimeOrigin::signed(2), 0, Some(3)),
+			Error::<Test>::NotRoleOwner
+		);
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(1), 0, Some(2)));
+		System::assert_last_event(
+			Event::RoleOwnerProposed { role: 0, owner: Some(2), who: Some(1) }.into(),
+		);
+		assert_eq!(Rbac::pending_owner_of(0), Some(2));
+		assert_noop!(
+			Rbac::accept_ownership(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotPendingOwner
+		);
+		assert_eq!(Rbac::owner_of(0), Some(1));
+
+		assert_ok!(Rbac::accept_ownership(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::RoleOwnerChanged { role: 0, owner: Some(2) }.into());
+		assert_eq!(Rbac::owner_of(0), Some(2));
+		assert_eq!(Rbac::pending_owner_of(0), None);
+		assert_eq!(OwnedRoleCount::<Test>::get(1), 0);
+		assert_eq!(OwnedRoleCount::<Test>::get(2), 1);
+		assert!(OwnedRoles::<Test>::contains_key(2, 0));
+		assert!(!OwnedRoles::<Test>::contains_key(1, 0));
+
+		// The owner manages the role, the previous owner no longer does.
+		assert_ok!(Rbac::update_role(RuntimeOrigin::signed(2), 0, bounded(b"owned"), bounded(b"")));
+		assert_noop!(
+			Rbac::propose_owner(RuntimeOrigin::signed(1), 0, Some(1)),
+			Error::<Test>::NotRoleOwner
+		);
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::root(), 0, Some(3)));
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(2), 0, None));
+		assert_eq!(Rbac::pending_owner_of(0), None);
+
+		assert_ok!(Rbac::delete_role(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Rbac::owner_of(0), None);
+		assert_eq!(OwnedRoleCount::<Test>::get(2), 0);
+	});
+}
+
+#[test]
+fn roles_of_a_reaped_owner_fall_back_to_their_admins() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		create_roles(2);
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 2, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(2), 1));
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 0));
+		System::assert_has_event(Event::RoleOrphaned { role: 0, owner: 1 }.into());
+		System::assert_has_event(Event::RoleOrphaned { role: 1, owner: 1 }.into());
+		assert_eq!(Rbac::owner_of(0), None);
+		assert_eq!(OwnedRoleCount::<Test>::get(1), 0);
+		assert_eq!(OwnedRoles::<Test>::iter_prefix(1).count(), 0);
+
+		// Role 0 is now managed by holders of its admin role, role 1 only by `AdminOrigin`.
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(2), 0, Some(3)));
+		assert_ok!(Rbac::accept_ownership(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Rbac::owner_of(0), Some(3));
+		assert_noop!(
+			Rbac::propose_owner(RuntimeOrigin::signed(2), 1, Some(2)),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::root(), 1, Some(2)));
+	});
+}
+
+#[test]
+fn accepting_ownership_moves_the_role_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_deposits(10, 0, 0);
+		for account in [1, 2, 4] {
+			assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), account, 100));
+		}
+		create_roles(1);
+
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(1), 0, Some(3)));
+		assert_noop!(
+			Rbac::accept_ownership(RuntimeOrigin::signed(3), 0),
+			TokenError::FundsUnavailable
+		);
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(1), 0, Some(2)));
+		assert_ok!(Rbac::accept_ownership(RuntimeOrigin::signed(2), 0));
+		assert_eq!(
+			Rbac::deposit_of(0, DepositKind::Role),
+			Some(Deposit { depositor: 2, amount: 10 })
+		);
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+		assert_eq!(Balances::total_balance_on_hold(&2), 10);
+
+		// Proposals naming a reaped account are withdrawn.
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(2), 0, Some(4)));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 4, 0));
+		assert_eq!(Rbac::pending_owner_of(0), None);
+		assert_eq!(PendingOwnedRoles::<Test>::iter_prefix(4).count(), 0);
+	});
+}
+
+#[test]
+fn owned_roles_are_limited() {
+	new_test_ext().execute_with(|| {
+		let max: u32 = <Test as crate::Config>::MaxOwnedRoles::get();
+		create_roles(max);
+		assert_noop!(
+			Rbac::create_role(RuntimeOrigin::signed(1), bounded(b"r"), bounded(b"")),
+			Error::<Test>::TooManyOwnedRoles
+		);
+		assert_ok!(Rbac::create_role(RuntimeOrigin::signed(2), bounded(b"r"), bounded(b"")));
+		assert_ok!(Rbac::propose_owner(RuntimeOrigin::signed(2), max, Some(1)));
+		assert_noop!(
+			Rbac::accept_ownership(RuntimeOrigin::signed(1), max),
+			Error::<Test>::TooManyOwnedRoles
+		);
+	});
+}
+
+#[test]
+fn frozen_roles_allow_nothing_until_thawed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(3);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::pallet(0)));
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 0));
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 2, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 0, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(11), 0));
+		assert_ok!(Rbac::delegate_role(RuntimeOrigin::signed(10), 12, 1, None, 100, None));
+		assert!(Rbac::is_permitted(&10, &remark));
+
+		assert_noop!(Rbac::freeze_role(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+		assert_noop!(Rbac::freeze_role(RuntimeOrigin::signed(9), 3), Error::<Test>::RoleNotFound);
+		assert_ok!(Rbac::freeze_role(RuntimeOrigin::signed(9), 1));
+		System::assert_last_event(Event::RoleFrozen { role: 1 }.into());
+		assert!(Rbac::is_frozen(1));
+		assert_eq!(Rbac::frozen_since(1), Some(1));
+		assert_noop!(Rbac::freeze_role(RuntimeOrigin::signed(9), 1), Error::<Test>::AlreadyFrozen);
+
+		// The frozen role grants nothing, not even what it inherits, to holders and delegates.
+		let frozen = AccessDecision::Frozen { role: 0, permission: Permission::pallet(0) };
+		assert_eq!(Rbac::access_decision(&10, &remark), frozen);
+		assert_eq!(Rbac::access_decision(&12, &remark), frozen);
+		assert_eq!(Rbac::role_decision(1, &remark), frozen);
+		assert!(!Rbac::role_permits(1, &remark));
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::AccessDenied)));
+		assert!(Rbac::is_permitted(&11, &remark));
+		assert!(Rbac::role_permits(0, &remark));
+		// It confers no admin rights either, but its assignments are kept.
+		assert!(!Rbac::holds_or_inherits(&10, 1));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(10), 13, 2, None),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert!(Rbac::has_role(&10, 1));
+		assert_eq!(Rbac::session_of(&10), vec![1]);
+		// Nor can it be copied while it is frozen.
+		assert_noop!(
+			Rbac::clone_role(RuntimeOrigin::root(), 1, bounded(b"copy"), bounded(b"")),
+			Error::<Test>::RoleIsFrozen
+		);
+
+		// Deny rules of frozen roles still apply.
+		assert_ok!(Rbac::add_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(0)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 11, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(11), 1));
+		assert!(!Rbac::is_permitted(&11, &remark));
+		assert_ok!(Rbac::remove_deny_rule(RuntimeOrigin::root(), 1, Permission::pallet(0)));
+
+		assert_noop!(Rbac::thaw_role(RuntimeOrigin::root(), 1), DispatchError::BadOrigin);
+		assert_ok!(Rbac::thaw_role(RuntimeOrigin::signed(9), 1));
+		System::assert_last_event(Event::RoleThawed { role: 1 }.into());
+		assert_noop!(Rbac::thaw_role(RuntimeOrigin::signed(9), 1), Error::<Test>::NotFrozen);
+		assert!(Rbac::is_permitted(&10, &remark));
+		assert!(Rbac::is_permitted(&12, &remark));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::signed(10), 13, 2, None));
+	});
+}
+
+#[test]
+fn enforcement_mode_decides_what_check_access_does_with_denials() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		let info = remark.get_dispatch_info();
+		assert_eq!(Rbac::enforcement_mode(), EnforcementMode::Enforcing);
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::AccessDenied)));
+
+		assert_noop!(
+			Rbac::set_enforcement_mode(RuntimeOrigin::signed(1), EnforcementMode::Disabled),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Rbac::set_enforcement_mode(RuntimeOrigin::root(), EnforcementMode::AuditOnly));
+		System::assert_last_event(
+			Event::EnforcementModeSet { mode: EnforcementMode::AuditOnly }.into(),
+		);
+
+		// Denials are reported, but let through, and the check is charged.
+		assert_ok!(CheckAccess::<Test>::new().validate(&10, &remark, &info, 0));
+		let before = System::block_weight().total();
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_eq!(
+			System::block_weight().total(),
+			before + <() as crate::WeightInfo>::check_access()
+		);
+		System::assert_last_event(
+			Event::AccessDenialAudited {
+				who: 10,
+				permission: Some(Permission::new(0, 7)),
+				error: Error::<Test>::AccessDenied.into(),
+			}
+			.into(),
+		);
+
+		// Nothing is checked once disabled.
+		assert_ok!(Rbac::set_enforcement_mode(RuntimeOrigin::root(), EnforcementMode::Disabled));
+		let events = System::events().len();
+		assert_ok!(CheckAccess::<Test>::new().validate(&10, &remark, &info, 0));
+		assert_ok!(pre_dispatch(10, &remark));
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(Rbac::set_enforcement_mode(RuntimeOrigin::root(), EnforcementMode::Enforcing));
+		assert_eq!(pre_dispatch(10, &remark), Err(invalid(Error::<Test>::AccessDenied)));
+
+		// Calls exempted by the runtime are let through without a role.
+		let exempt = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = exempt.get_dispatch_info();
+		let events = System::events().len();
+		assert_ok!(CheckAccess::<Test>::new().validate(&10, &exempt, &info, 0));
+		assert_ok!(pre_dispatch(10, &exempt));
+		assert_eq!(System::events().len(), events);
+	});
+}
+
+#[test]
+fn break_glass_grants_its_role_briefly_and_requires_a_review() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(2);
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+		assert_ok!(Rbac::grant_permission(RuntimeOrigin::root(), 0, Permission::All));
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 20, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(20), 1));
+
+		assert_noop!(
+			Rbac::break_glass(RuntimeOrigin::signed(10)),
+			Error::<Test>::NotBreakGlassAccount
+		);
+		assert_noop!(
+			Rbac::set_break_glass_role(RuntimeOrigin::signed(20), 10, Some(0)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Rbac::set_break_glass_role(RuntimeOrigin::root(), 10, Some(0)));
+		System::assert_last_event(Event::BreakGlassRoleSet { account: 10, role: Some(0) }.into());
+
+		assert_ok!(Rbac::break_glass(RuntimeOrigin::signed(10)));
+		System::assert_last_event(
+			Event::BreakGlassActivated { id: 0, account: 10, role: 0, expires_at: 11 }.into(),
+		);
+		assert_eq!(
+			Rbac::break_glass_use(0),
+			Some(BreakGlassUse { account: 10, role: 0, activated_at: 1, expires_at: 11 })
+		);
+		assert_eq!(Rbac::unreviewed_break_glass_of(10), Some(0));
+		assert_eq!(Rbac::session_of(&10), vec![0]);
+		assert!(Rbac::is_permitted(&10, &remark));
+
+		// The role is only granted for the break-glass period.
+		System::set_block_number(11);
+		assert!(!Rbac::is_permitted(&10, &remark));
+		Rbac::on_idle(11, Weight::MAX);
+		assert!(Rbac::roles_of(&10).is_empty());
+
+		// Until the use is reviewed by another account, the glass cannot be broken again.
+		assert_noop!(
+			Rbac::break_glass(RuntimeOrigin::signed(10)),
+			Error::<Test>::BreakGlassUnreviewed
+		);
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 10, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(10), 1));
+		assert_noop!(
+			Rbac::review_break_glass(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::BreakGlassSelfReview
+		);
+		assert_noop!(
+			Rbac::review_break_glass(RuntimeOrigin::signed(30), 0),
+			Error::<Test>::NotRoleAdmin
+		);
+		assert_ok!(Rbac::review_break_glass(RuntimeOrigin::signed(20), 0));
+		System::assert_last_event(
+			Event::BreakGlassReviewed { id: 0, account: 10, reviewer: Some(20) }.into(),
+		);
+		assert_eq!(Rbac::break_glass_use(0), None);
+		assert_eq!(Rbac::unreviewed_break_glass_of(10), None);
+		assert_noop!(
+			Rbac::review_break_glass(RuntimeOrigin::root(), 0),
+			Error::<Test>::BreakGlassUseNotFound
+		);
+
+		assert_ok!(Rbac::break_glass(RuntimeOrigin::signed(10)));
+		System::assert_last_event(
+			Event::BreakGlassActivated { id: 1, account: 10, role: 0, expires_at: 21 }.into(),
+		);
+		assert_ok!(Rbac::review_break_glass(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::BreakGlassReviewed { id: 1, account: 10, reviewer: None }.into(),
+		);
+	});
+}
+
+#[test]
+fn grants_of_roles_requiring_approvals_execute_once_approved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(3);
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 20, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(20), 1));
+		for approver in [10, 20, 30, 31] {
+			assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), approver, 2, None));
+			assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(approver), 2));
+		}
+
+		let approval = GrantApproval { approver: 2, threshold: 2 };
+		// Holders of a senior role would be assigned the role without approvals.
+		assert_ok!(Rbac::link_roles(RuntimeOrigin::root(), 1, 0, 1));
+		assert_noop!(
+			Rbac::set_grant_approval(RuntimeOrigin::root(), 0, Some(approval)),
+			Error::<Test>::RoleHasSeniors
+		);
+		assert_ok!(Rbac::unlink_roles(RuntimeOrigin::root(), 1, 0));
+		assert_noop!(
+			Rbac::set_grant_approval(RuntimeOrigin::signed(20), 0, Some(approval)),
+			DispatchError::BadOrigin
+		);
+		for threshold in [0, 4] {
+			assert_noop!(
+				Rbac::set_grant_approval(
+					RuntimeOrigin::root(),
+					0,
+					Some(GrantApproval { approver: 2, threshold })
+				),
+				Error::<Test>::InvalidThreshold
+			);
+		}
+		assert_ok!(Rbac::set_grant_approval(RuntimeOrigin::root(), 0, Some(approval)));
+		System::assert_last_event(
+			Event::GrantApprovalSet { role: 0, approval: Some(approval) }.into(),
+		);
+
+		// Admins of the role can no longer pass it on directly.
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(20), 10, 0, None),
+			Error::<Test>::ApprovalRequired
+		);
+		assert_noop!(
+			Rbac::delegate_role(RuntimeOrigin::signed(20), 10, 0, None, 5, None),
+			Error::<Test>::ApprovalRequired
+		);
+		// Nor through a copy of it.
+		assert_ok!(Rbac::clone_role(RuntimeOrigin::signed(20), 0, bounded(b"copy"), bounded(b"")));
+		assert_eq!(Rbac::grant_approval_of(3), Some(approval));
+		assert_noop!(
+			Rbac::assign_role(RuntimeOrigin::signed(20), 10, 3, None),
+			Error::<Test>::ApprovalRequired
+		);
+
+		assert_ok!(Rbac::propose_grant(RuntimeOrigin::signed(20), 10, 0, None));
+		System::assert_last_event(
+			Event::GrantProposed { id: 0, account: 10, role: 0, proposer: 20 }.into(),
+		);
+		assert_eq!(Rbac::grant_proposal(0).map(|proposal| proposal.expires_at), Some(11));
+
+		assert_noop!(Rbac::approve_grant(RuntimeOrigin::signed(11), 0), Error::<Test>::NotApprover);
+		// Approvers must be independent of the proposal.
+		for account in [10, 20] {
+			assert_noop!(
+				Rbac::approve_grant(RuntimeOrigin::signed(account), 0),
+				Error::<Test>::ApproverNotIndependent
+			);
+		}
+		assert_ok!(Rbac::approve_grant(RuntimeOrigin::signed(30), 0));
+		System::assert_last_event(Event::GrantApproved { id: 0, approver: 30 }.into());
+		assert_noop!(
+			Rbac::approve_grant(RuntimeOrigin::signed(30), 0),
+			Error::<Test>::AlreadyApproved
+		);
+		assert!(!AccountRoles::<Test>::contains_key(10, 0));
+
+		assert_ok!(Rbac::approve_grant(RuntimeOrigin::signed(31), 0));
+		System::assert_last_event(
+			Event::GrantExecuted { id: 0, account: 10, role: 0, expires_at: None }.into(),
+		);
+		assert!(AccountRoles::<Test>::contains_key(10, 0));
+		assert_eq!(Rbac::grant_proposal(0), None);
+
+		assert_ok!(Rbac::set_grant_approval(RuntimeOrigin::root(), 0, None));
+		assert_noop!(
+			Rbac::propose_grant(RuntimeOrigin::signed(20), 11, 0, None),
+			Error::<Test>::NoApprovalRequired
+		);
+	});
+}
+
+#[test]
+fn grant_proposals_can_be_rejected_cancelled_or_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_roles(3);
+		assert_ok!(Rbac::set_role_admin(RuntimeOrigin::root(), 0, Some(1)));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 20, 1, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(20), 1));
+		assert_ok!(Rbac::assign_role(RuntimeOrigin::root(), 30, 2, None));
+		assert_ok!(Rbac::activate_role(RuntimeOrigin::signed(30), 2));
+		assert_ok!(Rbac::set_grant_approval(
+			RuntimeOrigin::root(),
+			0,
+			Some(GrantApproval { approver: 2, threshold: 1 })
+		));
+
+		assert_ok!(Rbac::propose_grant(RuntimeOrigin::signed(20), 10, 0, None));
+		assert_noop!(Rbac::reject_grant(RuntimeOrigin::signed(10), 0), Error::<Test>::NotApprover);
+		assert_ok!(Rbac::reject_grant(RuntimeOrigin::signed(30), 0));
+		System::assert_last_event(Event::GrantRejected { id: 0, who: 30 }.into());
+		assert_eq!(Rbac::grant_proposal(0), None);
+
+		assert_ok!(Rbac::propose_grant(RuntimeOrigin::signed(20), 10, 0, None));
+		assert_noop!(Rbac::cancel_grant(RuntimeOrigin::signed(30), 1), Error::<Test>::NotProposer);
+		assert_ok!(Rbac::cancel_grant(RuntimeOrigin::signed(20), 1));
+		System::assert_last_event(Event::GrantCancelled { id: 1, who: Some(20) }.into());
+		assert_ok!(Rbac::propose_grant(RuntimeOrigin::signed(20), 10, 0, None));
+		assert_ok!(Rbac::cancel_grant(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::GrantCancelled { id: 2, who: None }.into());
+		assert_noop!(
+			Rbac::approve_grant(RuntimeOrigin::signed(30), 2),
+			Error::<Test>::GrantProposalNotFound
+		);
+
+		// Proposals that are not approved within their lifetime expire.
+		assert_ok!(Rbac::propose_grant(RuntimeOrigin::signed(20), 10, 0, None));
+		System::set_block_number(11);
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			Rbac::approve_grant(RuntimeOrigin::signed(30), 3),
+			Error::<Test>::ProposalExpired
 		);
+		Rbac::on_idle(11, Weight::MAX);
+		System::assert_last_event(Event::GrantProposalExpired { id: 3 }.into());
+		assert_eq!(Rbac::grant_proposal(3), None);
+		assert!(!AccountRoles::<Test>::contains_key(10, 0));
 	});
 }
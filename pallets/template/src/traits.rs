@@ -0,0 +1,37 @@
+//! Traits the runtime implements to extend the RBAC pallet.
+
+use frame_support::pallet_prelude::*;
+
+/// Evaluates constraints on the arguments of calls, which permissions may carry.
+///
+/// The runtime defines the constraints it supports, e.g. a maximum transferred value or an
+/// allowlist of destinations, and how to check them against its decoded calls.
+pub trait ConstraintEvaluator<Call> {
+	/// A constraint on the arguments of a call.
+	type Constraint: Parameter + MaxEncodedLen;
+
+	/// Whether `call` satisfies `constraint`.
+	///
+	/// Should return `false` for calls the constraint does not apply to.
+	fn evaluate(constraint: &Self::Constraint, call: &Call) -> bool;
+}
+
+impl<Call> ConstraintEvaluator<Call> for () {
+	type Constraint = ();
+
+	fn evaluate(_: &(), _: &Call) -> bool {
+		true
+	}
+}
+
+/// Measures how much of the balance of the account dispatching a call the call spends.
+pub trait SpendOf<AccountId, Call, Balance> {
+	/// The value `call` transfers out of the balance of `who`, or `None` if it spends nothing.
+	fn spend_of(who: &AccountId, call: &Call) -> Option<Balance>;
+}
+
+impl<AccountId, Call, Balance> SpendOf<AccountId, Call, Balance> for () {
+	fn spend_of(_: &AccountId, _: &Call) -> Option<Balance> {
+		None
+	}
+}
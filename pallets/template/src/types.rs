@@ -0,0 +1,735 @@
+//! Types used by the RBAC pallet.
+
+use crate::{Config, ConstraintEvaluator};
+use frame_support::{
+	pallet_prelude::*, traits::fungible, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// Identifier of a role. Role ids are assigned sequentially by the pallet.
+pub type RoleId = u32;
+
+/// Bounded human readable name of a role.
+pub type RoleName<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+/// Bounded free-form metadata attached to a role.
+pub type RoleMetadata<T> = BoundedVec<u8, <T as Config>::MaxMetadataLength>;
+
+/// Balance of the currency spent by role holders.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Constraint on call arguments supported by the runtime.
+pub type ConstraintOf<T> = <<T as Config>::ConstraintEvaluator as ConstraintEvaluator<
+	<T as Config>::RuntimeCall,
+>>::Constraint;
+
+/// Bounded constraints on the arguments of calls covered by a permission.
+pub type PermissionConstraintsOf<T> =
+	BoundedVec<ConstraintOf<T>, <T as Config>::MaxConstraintsPerPermission>;
+
+/// Bounded key of an account attribute.
+pub type AttributeKey<T> = BoundedVec<u8, <T as Config>::MaxAttributeKeyLength>;
+
+/// Bounded value of an account attribute.
+pub type AttributeValue<T> = BoundedVec<u8, <T as Config>::MaxAttributeValueLength>;
+
+/// Bounded attributes of an account, as key and value pairs with distinct keys.
+pub type AccountAttributesOf<T> =
+	BoundedVec<(AttributeKey<T>, AttributeValue<T>), <T as Config>::MaxAttributesPerAccount>;
+
+/// Identifier of a separation of duty constraint.
+pub type ConstraintId = u32;
+
+/// Bounded set of roles covered by a separation of duty constraint.
+pub type ConstraintRoles<T> = BoundedVec<RoleId, <T as Config>::MaxRolesPerConstraint>;
+
+/// Definition of a role as stored in the registry.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RoleInfo<T: Config> {
+	/// Human readable name of the role.
+	pub name: RoleName<T>,
+	/// Free-form metadata, e.g. a description or an off-chain reference.
+	pub metadata: RoleMetadata<T>,
+	/// Number of permissions granted to the role.
+	pub permissions: u32,
+	/// Number of deny rules of the role.
+	pub deny_rules: u32,
+	/// Role whose holders administer this role. Falls back to the bootstrap admin role.
+	pub admin: Option<RoleId>,
+	/// Number of accounts the role is assigned to, including expired assignments that have not
+	/// been removed yet.
+	pub members: u32,
+	/// Maximum number of accounts the role may be assigned to, if limited.
+	pub max_members: Option<u32>,
+	/// Role an account must hold for this role to be assigned to it, if any.
+	pub prerequisite: Option<RoleId>,
+	/// Limit on how often each account may use each permission of the role, if any.
+	pub rate_limit: Option<RateLimit<BlockNumberFor<T>>>,
+	/// Budget each holder may spend per period through the permissions of the role, if limited.
+	pub allowance: Option<Allowance<BalanceOf<T>, BlockNumberFor<T>>>,
+}
+
+impl<T: Config> RoleInfo<T> {
+	/// Definition of a new role without permissions, deny rules, holders nor limits.
+	pub fn new(name: RoleName<T>, metadata: RoleMetadata<T>) -> Self {
+		Self {
+			name,
+			metadata,
+			permissions: 0,
+			deny_rules: 0,
+			admin: None,
+			members: 0,
+			max_members: None,
+			prerequisite: None,
+			rate_limit: None,
+			allowance: None,
+		}
+	}
+}
+
+/// Limit on how often a single account may use a permission.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<BlockNumber> {
+	/// Maximum number of calls per window.
+	pub calls: u32,
+	/// Length of a window, in blocks.
+	pub period: BlockNumber,
+}
+
+/// Budget an account may spend per period through the permissions of a role.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Allowance<Balance, BlockNumber> {
+	/// Value that may be spent per period.
+	pub amount: Balance,
+	/// Length of a period, in blocks.
+	pub period: BlockNumber,
+}
+
+/// Allowance left to an account in its current period.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AllowanceWindow<Balance, BlockNumber> {
+	/// Value that may still be spent during the period.
+	pub remaining: Balance,
+	/// Block at which the period ends and the allowance refills with the next spending.
+	pub refills_at: BlockNumber,
+}
+
+/// Calls an account dispatched using a rate limited permission during the current window.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UsageWindow<BlockNumber> {
+	/// Number of calls dispatched during the window.
+	pub calls: u32,
+	/// Block at which the window ends and a new one starts with the next call.
+	pub ends_at: BlockNumber,
+}
+
+/// When a separation of duty constraint applies.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SodKind {
+	/// The constraint limits the roles an account holds.
+	Static,
+	/// The constraint limits the roles active together in the session of an account.
+	Dynamic,
+}
+
+/// Separation of duty constraint: no account may be authorized for more than `max_held` of
+/// `roles`, whether it holds them directly or inherits them through the hierarchy.
+///
+/// Static constraints apply to the roles an account holds, dynamic constraints only to the roles
+/// it has activated in its session.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct SodConstraint<T: Config> {
+	/// The mutually exclusive roles.
+	pub roles: ConstraintRoles<T>,
+	/// Maximum number of `roles` a single account may be authorized for.
+	pub max_held: u32,
+	/// Whether the constraint applies to held or to active roles.
+	pub kind: SodKind,
+}
+
+/// Permission to dispatch calls of the runtime.
+///
+/// A call is identified by the index of its pallet in `construct_runtime!` and the index of the
+/// call within that pallet, which are the first two bytes of any encoded `RuntimeCall`. Besides
+/// single calls, a permission may cover every call of a pallet or every call of the runtime.
+///
+/// Permissions are ordered from the most to the least specific: call permissions come first,
+/// then pallet wildcards, then the runtime wildcard.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum Permission {
+	/// Permission to dispatch a single call.
+	Call {
+		/// Index of the pallet in the runtime.
+		pallet_index: u8,
+		/// Index of the call within the pallet.
+		call_index: u8,
+	},
+	/// Permission to dispatch every call of a pallet.
+	Pallet {
+		/// Index of the pallet in the runtime.
+		pallet_index: u8,
+	},
+	/// Permission to dispatch every call of the runtime.
+	All,
+}
+
+impl Permission {
+	/// Create a permission for the call `call_index` of the pallet `pallet_index`.
+	pub const fn new(pallet_index: u8, call_index: u8) -> Self {
+		Self::Call { pallet_index, call_index }
+	}
+
+	/// Create a permission for every call of the pallet `pallet_index`.
+	pub const fn pallet(pallet_index: u8) -> Self {
+		Self::Pallet { pallet_index }
+	}
+
+	/// The permissions covering this one, from the most to the least specific, this one included.
+	pub fn covering(self) -> impl Iterator<Item = Self> {
+		let (call, pallet) = match self {
+			Self::Call { pallet_index, .. } => (Some(self), Some(Self::pallet(pallet_index))),
+			Self::Pallet { .. } => (None, Some(self)),
+			Self::All => (None, None),
+		};
+		[call, pallet, Some(Self::All)].into_iter().flatten()
+	}
+
+	/// Whether this permission covers `other`.
+	pub fn covers(&self, other: &Self) -> bool {
+		other.covering().any(|permission| permission == *self)
+	}
+
+	/// The permission required to dispatch `call`.
+	///
+	/// Returns `None` if `call` does not encode to at least two bytes, which never happens for a
+	/// `RuntimeCall` generated by `construct_runtime!`.
+	pub fn from_call<C: Encode>(call: &C) -> Option<Self> {
+		let mut prefix = CallIndexPrefix::default();
+		call.encode_to(&mut prefix);
+		match prefix.len {
+			2 => Some(Self::new(prefix.bytes[0], prefix.bytes[1])),
+			_ => None,
+		}
+	}
+}
+
+/// Outcome of checking whether a call may be dispatched, naming the rule that decided it.
+///
+/// Deny rules win over allows: a call is denied as soon as any role in scope carries a deny rule
+/// covering it, whatever allows the other roles carry. Otherwise it is allowed by the first allow
+/// covering it. In both cases the most specific rule is reported (call, then pallet, then
+/// runtime), and ties between roles go to the lowest role id.
+///
+/// Frozen roles, and the roles inherited through them, allow nothing, but their deny rules still
+/// apply.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AccessDecision {
+	/// The call is allowed by `permission` of `role`, reached through `delegation` if any.
+	Allowed { role: RoleId, permission: Permission, delegation: Option<DelegationId> },
+	/// The call is denied by the deny rule `permission` of `role`.
+	Denied { role: RoleId, permission: Permission },
+	/// No role in scope allows the call.
+	NotPermitted,
+	/// The call would be allowed by `permission` of `role`, but `role` is frozen or only
+	/// inherited through a frozen role.
+	Frozen { role: RoleId, permission: Permission },
+}
+
+impl AccessDecision {
+	/// Whether the call is allowed.
+	pub fn is_allowed(&self) -> bool {
+		matches!(self, Self::Allowed { .. })
+	}
+}
+
+/// How [`CheckAccess`](crate::CheckAccess) enforces access decisions on signed transactions.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EnforcementMode {
+	/// Transactions whose signer may not dispatch their call are rejected.
+	Enforcing,
+	/// Transactions whose signer may not dispatch their call are let through, and reported by
+	/// an event when dispatched.
+	AuditOnly,
+	/// Access is not checked.
+	Disabled,
+}
+
+/// Encoding sink that only retains the pallet and call index of an encoded call.
+#[derive(Default)]
+struct CallIndexPrefix {
+	bytes: [u8; 2],
+	len: usize,
+}
+
+impl codec::Output for CallIndexPrefix {
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes.iter().take(self.bytes.len() - self.len) {
+			self.bytes[self.len] = *byte;
+			self.len += 1;
+		}
+	}
+}
+
+/// Assignment of a role to an account.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct RoleAssignment<BlockNumber> {
+	/// Block from which the assignment is no longer active, if any.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> RoleAssignment<BlockNumber> {
+	/// Whether the assignment is active at block `now`.
+	pub fn is_active(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(true, |expires_at| now < expires_at)
+	}
+}
+
+/// Identifier of a delegation. Delegation ids are assigned sequentially by the pallet.
+pub type DelegationId = u32;
+
+/// Bounded subset of the permissions of a role.
+pub type DelegatedPermissions<T> = BoundedVec<Permission, <T as Config>::MaxPermissionsPerRole>;
+
+/// Delegation of a role, or of a subset of its permissions, from one account to another.
+///
+/// The original delegation of a chain is backed by an assignment of the role to its delegator,
+/// every further delegation by its `parent`, whose delegate is the delegator.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct Delegation<T: Config> {
+	/// Account delegating the role.
+	pub delegator: T::AccountId,
+	/// Account receiving the role.
+	pub delegate: T::AccountId,
+	/// The delegated role.
+	pub role: RoleId,
+	/// The delegated permissions of the role, or `None` for all of them.
+	pub permissions: Option<DelegatedPermissions<T>>,
+	/// The delegation this one was derived from, `None` if it is backed by an assignment.
+	pub parent: Option<DelegationId>,
+	/// Number of delegations in the chain ending with this one.
+	pub depth: u32,
+	/// Block from which the delegation is no longer effective.
+	pub expires_at: BlockNumberFor<T>,
+}
+
+/// Operation of a [`Condition`].
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum ConditionOp<T: Config> {
+	/// Push whether the current block is at least `from` and lower than `to`.
+	BlockRange { from: BlockNumberFor<T>, to: BlockNumberFor<T> },
+	/// Push whether the free balance of the caller exceeds the value.
+	FreeBalanceAbove(BalanceOf<T>),
+	/// Push whether the caller has the attribute `key` set to `value`.
+	AttributeEquals { key: AttributeKey<T>, value: AttributeValue<T> },
+	/// Pop two values and push whether both are true.
+	And,
+	/// Pop two values and push whether either is true.
+	Or,
+	/// Pop a value and push its negation.
+	Not,
+}
+
+/// Condition under which a permission allows calls, as a boolean expression in postfix notation.
+///
+/// Operations are evaluated in order on a stack: predicates push their value, operators pop their
+/// operands and push their result. A well formed condition leaves exactly one value, e.g.
+/// `[BlockRange { .. }, AttributeEquals { .. }, Not, And]` holds within the block range for callers
+/// without the attribute. Evaluating a condition costs at most one read of the attributes and one
+/// read of the balance of the caller, plus a step per operation.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct Condition<T: Config>(pub BoundedVec<ConditionOp<T>, T::MaxConditionLength>);
+
+impl<T: Config> Condition<T> {
+	/// Number of operations of the condition.
+	pub fn len(&self) -> u32 {
+		self.0.len() as u32
+	}
+
+	/// Whether the condition has no operation.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Whether every operator has its operands and exactly one value is left.
+	pub fn is_well_formed(&self) -> bool {
+		let mut depth = 0u32;
+		for op in self.0.iter() {
+			depth = match op {
+				ConditionOp::And | ConditionOp::Or if depth >= 2 => depth - 1,
+				ConditionOp::Not if depth >= 1 => depth,
+				ConditionOp::And | ConditionOp::Or | ConditionOp::Not => return false,
+				_ => depth + 1,
+			};
+		}
+		depth == 1
+	}
+
+	/// Evaluate the condition, using `predicate` for the value of each predicate.
+	///
+	/// Returns `false` if the condition is not well formed.
+	pub fn evaluate(&self, mut predicate: impl FnMut(&ConditionOp<T>) -> bool) -> bool {
+		let mut stack = sp_std::vec::Vec::with_capacity(self.0.len());
+		for op in self.0.iter() {
+			let value = match op {
+				ConditionOp::And | ConditionOp::Or => {
+					let (Some(b), Some(a)) = (stack.pop(), stack.pop()) else { return false };
+					if matches!(op, ConditionOp::And) {
+						a && b
+					} else {
+						a || b
+					}
+				},
+				ConditionOp::Not => match stack.pop() {
+					Some(a) => !a,
+					None => return false,
+				},
+				_ => predicate(op),
+			};
+			stack.push(value);
+		}
+		stack.len() == 1 && stack[0]
+	}
+}
+
+/// Version of the policy, bumped by every change to roles, their permissions, deny rules,
+/// constraints and conditions, the hierarchy and the bootstrap admin role.
+pub type PolicyVersion = u32;
+
+/// Bounded human readable name of a policy snapshot.
+pub type SnapshotName<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+/// Bounded snapshots of the policy, as versions and names sorted by version.
+pub type SnapshotsOf<T> = BoundedVec<(PolicyVersion, SnapshotName<T>), <T as Config>::MaxSnapshots>;
+
+/// Maximum number of changes a single version of the policy makes: syncing a role with its
+/// template changes its definition and may revoke every permission and deny rule it carries
+/// while granting as many others, and each operation of a policy batch changes up to two parts
+/// of the policy.
+pub struct MaxPolicyChanges<T>(PhantomData<T>);
+
+impl<T: Config> Get<u32> for MaxPolicyChanges<T> {
+	fn get() -> u32 {
+		T::MaxPermissionsPerRole::get()
+			.saturating_mul(2)
+			.saturating_add(1)
+			.max(T::MaxPolicyBatchLength::get().saturating_mul(2))
+	}
+}
+
+/// Part of the policy as it was before a change, recorded to roll the change back.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum PolicyChange<T: Config> {
+	/// The definition of `role`, `None` if it did not exist.
+	Role { role: RoleId, info: Option<RoleInfo<T>> },
+	/// Whether `permission` was granted to `role`, with its constraints and condition.
+	Permission {
+		role: RoleId,
+		permission: Permission,
+		granted: bool,
+		constraints: Option<PermissionConstraintsOf<T>>,
+		condition: Option<Condition<T>>,
+	},
+	/// Whether `role` carried the deny rule `permission`.
+	DenyRule { role: RoleId, permission: Permission, added: bool },
+	/// Whether `senior` inherited `junior` directly.
+	Link { senior: RoleId, junior: RoleId, linked: bool },
+	/// The bootstrap admin role.
+	BootstrapAdmin(Option<RoleId>),
+	/// The approvals required for assigning `role`, `None` if none were.
+	GrantApproval { role: RoleId, approval: Option<GrantApproval> },
+}
+
+/// Rollback of the policy to a snapshot, undoing one version at a time.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Rollback {
+	/// Version of the policy when the rollback started.
+	pub from: PolicyVersion,
+	/// Version of the snapshot being restored.
+	pub to: PolicyVersion,
+	/// Number of changes undone so far.
+	pub changes: u32,
+	/// Number of roles created since the snapshot that were kept because they are still
+	/// assigned or covered by a separation of duty constraint.
+	pub kept_roles: u32,
+}
+
+/// Operation of a policy batch, applied as the call of the same name would.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum PolicyOp<T: Config> {
+	/// Grant `permission` to `role`.
+	GrantPermission { role: RoleId, permission: Permission },
+	/// Revoke `permission` from `role`.
+	RevokePermission { role: RoleId, permission: Permission },
+	/// Deny `permission` to `role`.
+	AddDenyRule { role: RoleId, permission: Permission },
+	/// Remove the deny rule `permission` from `role`.
+	RemoveDenyRule { role: RoleId, permission: Permission },
+	/// Make `senior` inherit `junior`, with `holders` as upper bound on the holders to check.
+	LinkRoles { senior: RoleId, junior: RoleId, holders: u32 },
+	/// Make `senior` stop inheriting `junior`.
+	UnlinkRoles { senior: RoleId, junior: RoleId },
+	/// Assign `role` to `account`, optionally until the block `expires_at`.
+	AssignRole { account: T::AccountId, role: RoleId, expires_at: Option<BlockNumberFor<T>> },
+	/// Revoke `role` from `account`.
+	RevokeRole { account: T::AccountId, role: RoleId },
+}
+
+impl<T: Config> PolicyOp<T> {
+	/// The roles whose admin role the origin must hold to apply the operation.
+	pub fn roles(&self) -> sp_std::vec::Vec<RoleId> {
+		match self {
+			Self::GrantPermission { role, .. } |
+			Self::RevokePermission { role, .. } |
+			Self::AddDenyRule { role, .. } |
+			Self::RemoveDenyRule { role, .. } |
+			Self::AssignRole { role, .. } |
+			Self::RevokeRole { role, .. } => sp_std::vec![*role],
+			Self::LinkRoles { senior, junior, .. } | Self::UnlinkRoles { senior, junior } =>
+				sp_std::vec![*senior, *junior],
+		}
+	}
+
+	/// Whether the operation changes the policy, rather than assignments.
+	pub fn changes_policy(&self) -> bool {
+		!matches!(self, Self::AssignRole { .. } | Self::RevokeRole { .. })
+	}
+}
+
+/// Bounded list of operations applied together by `apply_policy_batch`.
+pub type PolicyBatch<T> = BoundedVec<PolicyOp<T>, <T as Config>::MaxPolicyBatchLength>;
+
+/// Identifier of a role template. Template ids are assigned sequentially by the pallet.
+pub type TemplateId = u32;
+
+/// Bounded set of distinct permissions of a role template.
+pub type TemplatePermissions<T> = BoundedVec<Permission, <T as Config>::MaxPermissionsPerRole>;
+
+/// Set of permissions and deny rules roles can be created with.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RoleTemplate<T: Config> {
+	/// Human readable name of the template.
+	pub name: RoleName<T>,
+	/// The permissions granted to roles created from the template.
+	pub permissions: TemplatePermissions<T>,
+	/// The deny rules of roles created from the template.
+	pub deny_rules: TemplatePermissions<T>,
+	/// Revision of the template, bumped by every update.
+	pub revision: u32,
+	/// Number of roles tracking the template.
+	pub derived: u32,
+}
+
+/// Template tracked by a role, so that updates of the template can be propagated to the role.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TemplateTracking {
+	/// The tracked template.
+	pub template: TemplateId,
+	/// Revision of the template the permissions and deny rules of the role were last set to.
+	pub revision: u32,
+}
+
+/// Part of the policy of a role a deposit is held for.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DepositKind {
+	/// The definition of the role.
+	Role,
+	/// A permission granted to the role.
+	Permission(Permission),
+	/// A deny rule of the role.
+	DenyRule(Permission),
+	/// The constraints carried by a permission of the role.
+	Constraints(Permission),
+	/// The condition carried by a permission of the role.
+	Condition(Permission),
+}
+
+/// Funds held from an account for a part of the policy it created.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Deposit<AccountId, Balance> {
+	/// The account the funds are held from.
+	pub depositor: AccountId,
+	/// The amount held.
+	pub amount: Balance,
+}
+
+/// Identifier of a use of break-glass access. Ids are assigned sequentially by the pallet.
+pub type BreakGlassId = u32;
+
+/// A use of break-glass access, kept until it is reviewed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BreakGlassUse<AccountId, BlockNumber> {
+	/// The account that broke the glass.
+	pub account: AccountId,
+	/// The role it was granted.
+	pub role: RoleId,
+	/// The block at which it broke the glass.
+	pub activated_at: BlockNumber,
+	/// The block at which the role was revoked again.
+	pub expires_at: BlockNumber,
+}
+
+/// Identifier of a grant proposal. Proposal ids are assigned sequentially by the pallet.
+pub type GrantProposalId = u32;
+
+/// Approvals required to assign a role: `threshold` distinct holders of the `approver` role must
+/// approve each assignment.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GrantApproval {
+	/// The role whose holders approve assignments.
+	pub approver: RoleId,
+	/// Number of approvals required.
+	pub threshold: u32,
+}
+
+/// Bounded list of the distinct accounts that approved a grant proposal.
+pub type GrantApprovers<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGrantApprovals>;
+
+/// Proposed assignment of a role requiring approvals, executed once approved by enough holders
+/// of the approver role.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct GrantProposal<T: Config> {
+	/// Account proposing the assignment.
+	pub proposer: T::AccountId,
+	/// Account the role is proposed for.
+	pub account: T::AccountId,
+	/// The proposed role.
+	pub role: RoleId,
+	/// Block from which the assignment would no longer be active, if any.
+	pub assignment_expires_at: Option<BlockNumberFor<T>>,
+	/// The accounts that approved the proposal so far.
+	pub approvals: GrantApprovers<T>,
+	/// Block from which the proposal can no longer be approved.
+	pub expires_at: BlockNumberFor<T>,
+}
+
+/// A call whose access is checked, along with the account dispatching it if known.
+pub(crate) struct Request<'a, T: Config> {
+	/// The checked call.
+	pub call: &'a <T as Config>::RuntimeCall,
+	/// The account dispatching the call, if known.
+	pub caller: Option<&'a T::AccountId>,
+	/// Weight spent evaluating conditions so far.
+	pub weight: sp_std::cell::Cell<frame_support::weights::Weight>,
+}
+
+impl<'a, T: Config> Request<'a, T> {
+	pub fn new(call: &'a <T as Config>::RuntimeCall, caller: Option<&'a T::AccountId>) -> Self {
+		Self { call, caller, weight: Default::default() }
+	}
+}
@@ -1,29 +1,20 @@
-
-//! Autogenerated weights for pallet_template
+//! Estimated weights for pallet_template.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! These weights were NOT generated by the Substrate benchmark CLI and have not been measured.
+//! They are hand-written estimates based on the storage each call accesses in its worst case,
+//! and must be replaced before production use by running the benchmarks in `benchmarking.rs`:
+//!
+//! ```text
+//! ./target/release/node-template benchmark pallet \
+//!     --chain dev \
+//!     --pallet pallet_template \
+//!     --extrinsic '*' \
+//!     --steps 50 \
+//!     --repeat 20 \
+//!     --wasm-execution compiled \
+//!     --output pallets/template/src/weights.rs \
+//!     --template .maintain/frame-weight-template.hbs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,57 +25,1794 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_template.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn create_role() -> Weight;
+	fn update_role() -> Weight;
+	fn delete_role(p: u32) -> Weight;
+	fn grant_permission() -> Weight;
+	fn revoke_permission() -> Weight;
+	fn assign_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn link_roles(d: u32, h: u32) -> Weight;
+	fn unlink_roles() -> Weight;
+	fn expire_role() -> Weight;
+	fn set_role_admin() -> Weight;
+	fn set_bootstrap_admin() -> Weight;
+	fn add_sod_constraint(r: u32, h: u32) -> Weight;
+	fn remove_sod_constraint() -> Weight;
+	fn activate_role() -> Weight;
+	fn deactivate_role() -> Weight;
+	fn delegate_role(d: u32) -> Weight;
+	fn revoke_delegation() -> Weight;
+	fn expire_delegation() -> Weight;
+	fn set_max_members() -> Weight;
+	fn set_prerequisite(m: u32) -> Weight;
+	fn add_deny_rule() -> Weight;
+	fn remove_deny_rule() -> Weight;
+	fn set_permission_constraints() -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn expire_usage_window() -> Weight;
+	fn check_access() -> Weight;
+	fn set_allowance() -> Weight;
+	fn expire_allowance_window() -> Weight;
+	fn set_attribute() -> Weight;
+	fn set_permission_condition() -> Weight;
+	fn evaluate_condition(n: u32) -> Weight;
+	fn take_snapshot() -> Weight;
+	fn remove_snapshot() -> Weight;
+	fn rollback_to() -> Weight;
+	fn undo_policy_version(c: u32) -> Weight;
+	fn create_template() -> Weight;
+	fn update_template() -> Weight;
+	fn remove_template() -> Weight;
+	fn instantiate_template(p: u32) -> Weight;
+	fn clone_role(p: u32) -> Weight;
+	fn sync_role(p: u32) -> Weight;
+	fn untrack_template() -> Weight;
+	fn propose_owner() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn freeze_role() -> Weight;
+	fn thaw_role() -> Weight;
+	fn set_enforcement_mode() -> Weight;
+	fn set_break_glass_role() -> Weight;
+	fn break_glass() -> Weight;
+	fn review_break_glass() -> Weight;
+	fn set_grant_approval() -> Weight;
+	fn propose_grant() -> Weight;
+	fn approve_grant() -> Weight;
+	fn reject_grant() -> Weight;
+	fn cancel_grant() -> Weight;
+	fn expire_grant_proposal() -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Estimated weights for pallet_template on the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn create_role() -> Weight {
+		Weight::from_parts(31_500_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn update_role() -> Weight {
+		Weight::from_parts(32_030_500_000, 563831)
+			.saturating_add(T::DbWeight::get().reads(15688_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac RoleMembers (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac RoleSeniors (r:1 w:0)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac RolePermissions (r:32 w:32)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleDenyRules (r:32 w:0)
+	/// Storage: Rbac PermissionConstraints (r:32 w:32)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:32 w:32)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:34)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac PendingOwners (r:0 w:1)
+	/// Storage: Rbac FrozenRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:97 w:97)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac PendingOwnedRoles (r:0 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn delete_role(p: u32) -> Weight {
+		Weight::from_parts(32_050_000_000, 569859)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(15696_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7641).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn grant_permission() -> Weight {
+		Weight::from_parts(32_043_000_000, 565550)
+			.saturating_add(T::DbWeight::get().reads(15693_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:3 w:3)
+	/// Storage: Balances Holds (r:3 w:3)
+	/// Storage: System Account (r:3 w:3)
+	fn revoke_permission() -> Weight {
+		Weight::from_parts(32_062_000_000, 565550)
+			.saturating_add(T::DbWeight::get().reads(15699_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac RoleJuniors (r:22767 w:0)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:80 w:0)
+	/// Storage: Rbac AccountRoles (r:48 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac RoleConstraints (r:146 w:0)
+	/// Storage: Rbac SodConstraints (r:57 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	fn assign_role() -> Weight {
+		Weight::from_parts(62_000_000_000, 1470000)
+			.saturating_add(T::DbWeight::get().reads(30448_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:17 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:17 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac SessionRoles (r:0 w:1)
+	/// Storage: Rbac DelegationsBy (r:1 w:0)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(32_055_000_000, 563555)
+			.saturating_add(T::DbWeight::get().reads(15702_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:32 w:0)
+	/// Storage: Rbac RoleJuniors (r:16679 w:1)
+	/// Storage: Rbac RoleSeniors (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:2 w:0)
+	/// Storage: Rbac SodConstraints (r:1 w:0)
+	/// Storage: Rbac RoleMembers (r:1 w:0)
+	/// Storage: Rbac RoleDelegations (r:1 w:0)
+	/// Storage: Rbac DelegationsTo (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:35 w:0)
+	/// Storage: Rbac FrozenRoles (r:14624 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// The range of component `d` is `[1, 8]`.
+	/// The range of component `h` is `[0, 1000]`.
+	fn link_roles(d: u32, h: u32) -> Weight {
+		Weight::from_parts(64_061_000_000, 1126226)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(9_200_000, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(31375_u64))
+			.saturating_add(T::DbWeight::get().reads((152_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 19992).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 5075).saturating_mul(h.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:32 w:0)
+	/// Storage: Rbac RoleJuniors (r:16672 w:1)
+	/// Storage: Rbac RoleSeniors (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:34 w:0)
+	/// Storage: Rbac FrozenRoles (r:14624 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn unlink_roles() -> Weight {
+		Weight::from_parts(64_029_500_000, 1123519)
+			.saturating_add(T::DbWeight::get().reads(31368_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:2 w:1)
+	/// Storage: Rbac AccountRoles (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: System Number (r:1 w:0)
+	/// Storage: System Events (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:0 w:1)
+	/// Storage: Rbac DelegationsBy (r:1 w:0)
+	fn expire_role() -> Weight {
+		Weight::from_parts(36_000_000, 6058)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac Roles (r:3 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_role_admin() -> Weight {
+		Weight::from_parts(32_036_500_000, 568480)
+			.saturating_add(T::DbWeight::get().reads(15689_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_bootstrap_admin() -> Weight {
+		Weight::from_parts(17_500_000, 3840)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:9 w:0)
+	/// Storage: Rbac NextConstraintId (r:1 w:1)
+	/// Storage: Rbac SodConstraints (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:8 w:8)
+	/// Storage: Rbac RoleSeniors (r:8 w:0)
+	/// Storage: Rbac RoleMembers (r:1001 w:0)
+	/// Storage: Rbac RoleDelegations (r:8 w:0)
+	/// Storage: Rbac DelegationsTo (r:1000 w:0)
+	/// Storage: Rbac AccountRoles (r:1000 w:0)
+	/// Storage: Rbac RoleJuniors (r:1 w:0)
+	/// The range of component `r` is `[2, 8]`.
+	/// The range of component `h` is `[0, 1000]`.
+	fn add_sod_constraint(r: u32, h: u32) -> Weight {
+		Weight::from_parts(19_000_000, 3544)
+			.saturating_add(Weight::from_parts(6_800_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(11_400_000, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2820).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 5075).saturating_mul(h.into()))
+	}
+	/// Storage: Rbac SodConstraints (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:0 w:8)
+	fn remove_sod_constraint() -> Weight {
+		Weight::from_parts(21_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac AccountRoles (r:34 w:0)
+	/// Storage: Rbac SessionRoles (r:18 w:1)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:80 w:0)
+	/// Storage: Rbac RoleJuniors (r:8354 w:0)
+	/// Storage: Rbac RoleConstraints (r:1043 w:0)
+	/// Storage: Rbac SodConstraints (r:1 w:0)
+	fn activate_role() -> Weight {
+		Weight::from_parts(18_061_000_000, 779000)
+			.saturating_add(T::DbWeight::get().reads(9547_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac SessionRoles (r:1 w:1)
+	fn deactivate_role() -> Weight {
+		Weight::from_parts(13_000_000, 3525)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac Delegations (r:64 w:1)
+	/// Storage: Rbac AccountRoles (r:50 w:0)
+	/// Storage: Rbac RolePermissions (r:122 w:0)
+	/// Storage: Rbac RoleJuniors (r:21000 w:0)
+	/// Storage: Rbac RoleConstraints (r:292 w:0)
+	/// Storage: Rbac SodConstraints (r:114 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac DelegationsBy (r:1 w:1)
+	/// Storage: Rbac DelegationsTo (r:32 w:1)
+	/// Storage: Rbac NextDelegationId (r:1 w:1)
+	/// Storage: Rbac RoleDelegations (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:2 w:0)
+	/// Storage: Rbac DelegationExpiries (r:0 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// The range of component `d` is `[1, 4]`.
+	fn delegate_role(d: u32) -> Weight {
+		Weight::from_parts(45_000_000_000, 1365000)
+			.saturating_add(Weight::from_parts(7_300_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(21774_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2634).saturating_mul(d.into()))
+	}
+	/// Storage: Rbac Delegations (r:1 w:1)
+	/// Storage: Rbac DelegationsBy (r:0 w:1)
+	/// Storage: Rbac DelegationsTo (r:0 w:1)
+	/// Storage: Rbac RoleDelegations (r:0 w:1)
+	/// Storage: Rbac DelegationExpiries (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	fn revoke_delegation() -> Weight {
+		Weight::from_parts(32_024_000_000, 563624)
+			.saturating_add(T::DbWeight::get().reads(15682_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac DelegationExpiries (r:1 w:1)
+	/// Storage: Rbac Delegations (r:1 w:1)
+	/// Storage: Rbac DelegationsBy (r:0 w:1)
+	/// Storage: Rbac DelegationsTo (r:0 w:1)
+	/// Storage: Rbac RoleDelegations (r:0 w:1)
+	fn expire_delegation() -> Weight {
+		Weight::from_parts(22_000_000, 3624)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_max_members() -> Weight {
+		Weight::from_parts(32_026_500_000, 563854)
+			.saturating_add(T::DbWeight::get().reads(15687_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:2 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:2016 w:0)
+	/// Storage: Rbac RoleMembers (r:1001 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_prerequisite(m: u32) -> Weight {
+		Weight::from_parts(32_031_500_000, 566844)
+			.saturating_add(Weight::from_parts(12_600_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(15688_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2540).saturating_mul(m.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleDenyRules (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn add_deny_rule() -> Weight {
+		Weight::from_parts(32_040_000_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15691_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleDenyRules (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_deny_rule() -> Weight {
+		Weight::from_parts(32_040_000_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15691_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_permission_constraints() -> Weight {
+		Weight::from_parts(32_040_500_000, 565573)
+			.saturating_add(T::DbWeight::get().reads(15693_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(32_027_500_000, 563854)
+			.saturating_add(T::DbWeight::get().reads(15687_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:1 w:0)
+	/// Storage: Rbac DelegationExpiries (r:1 w:0)
+	/// Storage: Rbac UsageWindowExpiries (r:1 w:1)
+	/// Storage: Rbac UsageWindows (r:0 w:1)
+	fn expire_usage_window() -> Weight {
+		Weight::from_parts(17_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac AccountRoles (r:81 w:0)
+	/// Storage: Rbac RoleJuniors (r:28800 w:0)
+	/// Storage: Rbac FrozenRoles (r:1824 w:0)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:96 w:0)
+	/// Storage: Rbac RoleDenyRules (r:267 w:0)
+	/// Storage: Rbac RolePermissions (r:3003 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac UsageWindows (r:1 w:1)
+	/// Storage: Rbac UsageWindowExpiries (r:0 w:1)
+	fn check_access() -> Weight {
+		Weight::from_parts(62_000_000_000, 2100000)
+			.saturating_add(T::DbWeight::get().reads(34110_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_allowance() -> Weight {
+		Weight::from_parts(32_027_500_000, 563854)
+			.saturating_add(T::DbWeight::get().reads(15687_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:1 w:0)
+	/// Storage: Rbac DelegationExpiries (r:1 w:0)
+	/// Storage: Rbac UsageWindowExpiries (r:1 w:0)
+	/// Storage: Rbac AllowanceRefills (r:1 w:1)
+	/// Storage: Rbac AllowanceWindows (r:0 w:1)
+	fn expire_allowance_window() -> Weight {
+		Weight::from_parts(19_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac AccountAttributes (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(21_000_000, 5214)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:1)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_permission_condition() -> Weight {
+		Weight::from_parts(32_040_500_000, 565573)
+			.saturating_add(T::DbWeight::get().reads(15693_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac AccountAttributes (r:1 w:0)
+	/// Storage: System Account (r:1 w:0)
+	/// The range of component `n` is `[1, 16]`.
+	fn evaluate_condition(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 5214)
+			.saturating_add(Weight::from_parts(1_450_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:0)
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	fn take_snapshot() -> Weight {
+		Weight::from_parts(13_000_000, 2590)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	fn remove_snapshot() -> Weight {
+		Weight::from_parts(11_000_000, 2590)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac PendingRollback (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:0)
+	fn rollback_to() -> Weight {
+		Weight::from_parts(15_000_000, 2590)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:1)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:65 w:65)
+	/// Storage: Rbac RolePermissions (r:0 w:65)
+	/// Storage: Rbac PermissionConstraints (r:0 w:65)
+	/// Storage: Rbac PermissionConditions (r:0 w:65)
+	/// The range of component `c` is `[0, 65]`.
+	fn undo_policy_version(c: u32) -> Weight {
+		Weight::from_parts(16_000_000, 3481)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 6304).saturating_mul(c.into()))
+	}
+	/// Storage: Rbac NextTemplateId (r:1 w:1)
+	/// Storage: Rbac Templates (r:0 w:1)
+	fn create_template() -> Weight {
+		Weight::from_parts(14_500_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn update_template() -> Weight {
+		Weight::from_parts(17_000_000, 3756)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn remove_template() -> Weight {
+		Weight::from_parts(15_500_000, 3756)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Templates (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:33)
+	/// Storage: Rbac Roles (r:0 w:1)
+	/// Storage: Rbac RolePermissions (r:0 w:32)
+	/// Storage: Rbac RoleTemplates (r:0 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:65 w:65)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn instantiate_template(p: u32) -> Weight {
+		Weight::from_parts(45_000_000, 3756)
+			.saturating_add(Weight::from_parts(10_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5094).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7313 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:33)
+	/// Storage: Rbac RolePermissions (r:32 w:32)
+	/// Storage: Rbac PermissionConstraints (r:32 w:32)
+	/// Storage: Rbac PermissionConditions (r:32 w:32)
+	/// Storage: Rbac RoleDenyRules (r:1 w:0)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:129 w:129)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn clone_role(p: u32) -> Weight {
+		Weight::from_parts(32_055_500_000, 564583)
+			.saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(15698_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 10188).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:65)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:0)
+	/// Storage: Rbac RolePermissions (r:64 w:64)
+	/// Storage: Rbac PermissionConstraints (r:0 w:32)
+	/// Storage: Rbac PermissionConditions (r:0 w:32)
+	/// Storage: Rbac RoleDenyRules (r:1 w:0)
+	/// Storage: Rbac Deposits (r:96 w:96)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn sync_role(p: u32) -> Weight {
+		Weight::from_parts(32_049_000_000, 563756)
+			.saturating_add(Weight::from_parts(22_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(15692_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7641).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn untrack_template() -> Weight {
+		Weight::from_parts(32_021_500_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15685_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac RoleOwners (r:1 w:0)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingOwners (r:1 w:1)
+	/// Storage: Rbac PendingOwnedRoles (r:9 w:2)
+	fn propose_owner() -> Weight {
+		Weight::from_parts(32_039_500_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15694_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac PendingOwners (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:2 w:2)
+	/// Storage: Rbac OwnedRoles (r:0 w:2)
+	/// Storage: Rbac PendingOwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(54_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac FrozenRoles (r:1 w:1)
+	fn freeze_role() -> Weight {
+		Weight::from_parts(13_500_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac FrozenRoles (r:1 w:1)
+	fn thaw_role() -> Weight {
+		Weight::from_parts(10_500_000, 3481)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Enforcement (r:0 w:1)
+	fn set_enforcement_mode() -> Weight {
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BreakGlassRoles (r:0 w:1)
+	fn set_break_glass_role() -> Weight {
+		Weight::from_parts(15_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac BreakGlassRoles (r:1 w:0)
+	/// Storage: Rbac UnreviewedBreakGlass (r:1 w:1)
+	/// Storage: Rbac AccountRoles (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac DelegationsTo (r:1 w:0)
+	/// Storage: Rbac RoleJuniors (r:1 w:0)
+	/// Storage: Rbac RoleConstraints (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:1 w:1)
+	/// Storage: Rbac NextBreakGlassId (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac BreakGlassUses (r:0 w:1)
+	fn break_glass() -> Weight {
+		Weight::from_parts(42_500_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac BreakGlassUses (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac UnreviewedBreakGlass (r:0 w:1)
+	fn review_break_glass() -> Weight {
+		Weight::from_parts(32_024_000_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15684_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac RoleSeniors (r:1 w:0)
+	fn set_grant_approval() -> Weight {
+		Weight::from_parts(29_500_000, 9225)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac NextGrantProposalId (r:1 w:1)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	/// Storage: Rbac GrantProposals (r:0 w:1)
+	fn propose_grant() -> Weight {
+		Weight::from_parts(32_031_000_000, 563858)
+			.saturating_add(T::DbWeight::get().reads(15685_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:17 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:1 w:0)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	fn approve_grant() -> Weight {
+		Weight::from_parts(32_052_000_000, 564067)
+			.saturating_add(T::DbWeight::get().reads(15687_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	fn reject_grant() -> Weight {
+		Weight::from_parts(32_026_000_000, 564067)
+			.saturating_add(T::DbWeight::get().reads(15683_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	fn cancel_grant() -> Weight {
+		Weight::from_parts(16_500_000, 4067)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac GrantProposalExpiries (r:1 w:1)
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	fn expire_grant_proposal() -> Weight {
+		Weight::from_parts(18_000_000, 4067)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn create_role() -> Weight {
+		Weight::from_parts(31_500_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn update_role() -> Weight {
+		Weight::from_parts(32_030_500_000, 563831)
+			.saturating_add(RocksDbWeight::get().reads(15688_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac RoleMembers (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac RoleSeniors (r:1 w:0)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac RolePermissions (r:32 w:32)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleDenyRules (r:32 w:0)
+	/// Storage: Rbac PermissionConstraints (r:32 w:32)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:32 w:32)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:34)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac PendingOwners (r:0 w:1)
+	/// Storage: Rbac FrozenRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:97 w:97)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac PendingOwnedRoles (r:0 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn delete_role(p: u32) -> Weight {
+		Weight::from_parts(32_050_000_000, 569859)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(15696_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7641).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn grant_permission() -> Weight {
+		Weight::from_parts(32_043_000_000, 565550)
+			.saturating_add(RocksDbWeight::get().reads(15693_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:3 w:3)
+	/// Storage: Balances Holds (r:3 w:3)
+	/// Storage: System Account (r:3 w:3)
+	fn revoke_permission() -> Weight {
+		Weight::from_parts(32_062_000_000, 565550)
+			.saturating_add(RocksDbWeight::get().reads(15699_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac RoleJuniors (r:22767 w:0)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:80 w:0)
+	/// Storage: Rbac AccountRoles (r:48 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac RoleConstraints (r:146 w:0)
+	/// Storage: Rbac SodConstraints (r:57 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	fn assign_role() -> Weight {
+		Weight::from_parts(62_000_000_000, 1470000)
+			.saturating_add(RocksDbWeight::get().reads(30448_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:17 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:17 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac SessionRoles (r:0 w:1)
+	/// Storage: Rbac DelegationsBy (r:1 w:0)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(32_055_000_000, 563555)
+			.saturating_add(RocksDbWeight::get().reads(15702_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:32 w:0)
+	/// Storage: Rbac RoleJuniors (r:16679 w:1)
+	/// Storage: Rbac RoleSeniors (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:2 w:0)
+	/// Storage: Rbac SodConstraints (r:1 w:0)
+	/// Storage: Rbac RoleMembers (r:1 w:0)
+	/// Storage: Rbac RoleDelegations (r:1 w:0)
+	/// Storage: Rbac DelegationsTo (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:35 w:0)
+	/// Storage: Rbac FrozenRoles (r:14624 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// The range of component `d` is `[1, 8]`.
+	/// The range of component `h` is `[0, 1000]`.
+	fn link_roles(d: u32, h: u32) -> Weight {
+		Weight::from_parts(64_061_000_000, 1126226)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(9_200_000, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(31375_u64))
+			.saturating_add(RocksDbWeight::get().reads((152_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 19992).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 5075).saturating_mul(h.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:32 w:0)
+	/// Storage: Rbac RoleJuniors (r:16672 w:1)
+	/// Storage: Rbac RoleSeniors (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:34 w:0)
+	/// Storage: Rbac FrozenRoles (r:14624 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn unlink_roles() -> Weight {
+		Weight::from_parts(64_029_500_000, 1123519)
+			.saturating_add(RocksDbWeight::get().reads(31368_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:2 w:1)
+	/// Storage: Rbac AccountRoles (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: System Number (r:1 w:0)
+	/// Storage: System Events (r:1 w:1)
+	/// Storage: Rbac SessionRoles (r:0 w:1)
+	/// Storage: Rbac DelegationsBy (r:1 w:0)
+	fn expire_role() -> Weight {
+		Weight::from_parts(36_000_000, 6058)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac Roles (r:3 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_role_admin() -> Weight {
+		Weight::from_parts(32_036_500_000, 568480)
+			.saturating_add(RocksDbWeight::get().reads(15689_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_bootstrap_admin() -> Weight {
+		Weight::from_parts(17_500_000, 3840)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:9 w:0)
+	/// Storage: Rbac NextConstraintId (r:1 w:1)
+	/// Storage: Rbac SodConstraints (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:8 w:8)
+	/// Storage: Rbac RoleSeniors (r:8 w:0)
+	/// Storage: Rbac RoleMembers (r:1001 w:0)
+	/// Storage: Rbac RoleDelegations (r:8 w:0)
+	/// Storage: Rbac DelegationsTo (r:1000 w:0)
+	/// Storage: Rbac AccountRoles (r:1000 w:0)
+	/// Storage: Rbac RoleJuniors (r:1 w:0)
+	/// The range of component `r` is `[2, 8]`.
+	/// The range of component `h` is `[0, 1000]`.
+	fn add_sod_constraint(r: u32, h: u32) -> Weight {
+		Weight::from_parts(19_000_000, 3544)
+			.saturating_add(Weight::from_parts(6_800_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(11_400_000, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2820).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 5075).saturating_mul(h.into()))
+	}
+	/// Storage: Rbac SodConstraints (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:0 w:8)
+	fn remove_sod_constraint() -> Weight {
+		Weight::from_parts(21_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac AccountRoles (r:34 w:0)
+	/// Storage: Rbac SessionRoles (r:18 w:1)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:80 w:0)
+	/// Storage: Rbac RoleJuniors (r:8354 w:0)
+	/// Storage: Rbac RoleConstraints (r:1043 w:0)
+	/// Storage: Rbac SodConstraints (r:1 w:0)
+	fn activate_role() -> Weight {
+		Weight::from_parts(18_061_000_000, 779000)
+			.saturating_add(RocksDbWeight::get().reads(9547_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac SessionRoles (r:1 w:1)
+	fn deactivate_role() -> Weight {
+		Weight::from_parts(13_000_000, 3525)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac Delegations (r:64 w:1)
+	/// Storage: Rbac AccountRoles (r:50 w:0)
+	/// Storage: Rbac RolePermissions (r:122 w:0)
+	/// Storage: Rbac RoleJuniors (r:21000 w:0)
+	/// Storage: Rbac RoleConstraints (r:292 w:0)
+	/// Storage: Rbac SodConstraints (r:114 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac DelegationsBy (r:1 w:1)
+	/// Storage: Rbac DelegationsTo (r:32 w:1)
+	/// Storage: Rbac NextDelegationId (r:1 w:1)
+	/// Storage: Rbac RoleDelegations (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:2 w:0)
+	/// Storage: Rbac DelegationExpiries (r:0 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// The range of component `d` is `[1, 4]`.
+	fn delegate_role(d: u32) -> Weight {
+		Weight::from_parts(45_000_000_000, 1365000)
+			.saturating_add(Weight::from_parts(7_300_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(21774_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2634).saturating_mul(d.into()))
+	}
+	/// Storage: Rbac Delegations (r:1 w:1)
+	/// Storage: Rbac DelegationsBy (r:0 w:1)
+	/// Storage: Rbac DelegationsTo (r:0 w:1)
+	/// Storage: Rbac RoleDelegations (r:0 w:1)
+	/// Storage: Rbac DelegationExpiries (r:0 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	fn revoke_delegation() -> Weight {
+		Weight::from_parts(32_024_000_000, 563624)
+			.saturating_add(RocksDbWeight::get().reads(15682_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac DelegationExpiries (r:1 w:1)
+	/// Storage: Rbac Delegations (r:1 w:1)
+	/// Storage: Rbac DelegationsBy (r:0 w:1)
+	/// Storage: Rbac DelegationsTo (r:0 w:1)
+	/// Storage: Rbac RoleDelegations (r:0 w:1)
+	fn expire_delegation() -> Weight {
+		Weight::from_parts(22_000_000, 3624)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_max_members() -> Weight {
+		Weight::from_parts(32_026_500_000, 563854)
+			.saturating_add(RocksDbWeight::get().reads(15687_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:2 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:2016 w:0)
+	/// Storage: Rbac RoleMembers (r:1001 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_prerequisite(m: u32) -> Weight {
+		Weight::from_parts(32_031_500_000, 566844)
+			.saturating_add(Weight::from_parts(12_600_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(15688_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2540).saturating_mul(m.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleDenyRules (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn add_deny_rule() -> Weight {
+		Weight::from_parts(32_040_000_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15691_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RoleDenyRules (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:2)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_deny_rule() -> Weight {
+		Weight::from_parts(32_040_000_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15691_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:1)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_permission_constraints() -> Weight {
+		Weight::from_parts(32_040_500_000, 565573)
+			.saturating_add(RocksDbWeight::get().reads(15693_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(32_027_500_000, 563854)
+			.saturating_add(RocksDbWeight::get().reads(15687_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:1 w:0)
+	/// Storage: Rbac DelegationExpiries (r:1 w:0)
+	/// Storage: Rbac UsageWindowExpiries (r:1 w:1)
+	/// Storage: Rbac UsageWindows (r:0 w:1)
+	fn expire_usage_window() -> Weight {
+		Weight::from_parts(17_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac AccountRoles (r:81 w:0)
+	/// Storage: Rbac RoleJuniors (r:28800 w:0)
+	/// Storage: Rbac FrozenRoles (r:1824 w:0)
+	/// Storage: Rbac DelegationsTo (r:17 w:0)
+	/// Storage: Rbac Delegations (r:96 w:0)
+	/// Storage: Rbac RoleDenyRules (r:267 w:0)
+	/// Storage: Rbac RolePermissions (r:3003 w:0)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:0)
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac UsageWindows (r:1 w:1)
+	/// Storage: Rbac UsageWindowExpiries (r:0 w:1)
+	fn check_access() -> Weight {
+		Weight::from_parts(62_000_000_000, 2100000)
+			.saturating_add(RocksDbWeight::get().reads(34110_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	fn set_allowance() -> Weight {
+		Weight::from_parts(32_027_500_000, 563854)
+			.saturating_add(RocksDbWeight::get().reads(15687_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac RoleExpiries (r:1 w:0)
+	/// Storage: Rbac DelegationExpiries (r:1 w:0)
+	/// Storage: Rbac UsageWindowExpiries (r:1 w:0)
+	/// Storage: Rbac AllowanceRefills (r:1 w:1)
+	/// Storage: Rbac AllowanceWindows (r:0 w:1)
+	fn expire_allowance_window() -> Weight {
+		Weight::from_parts(19_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac AccountAttributes (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(21_000_000, 5214)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac RolePermissions (r:1 w:0)
+	/// Storage: Rbac PermissionConditions (r:1 w:1)
+	/// Storage: Rbac PermissionConstraints (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_permission_condition() -> Weight {
+		Weight::from_parts(32_040_500_000, 565573)
+			.saturating_add(RocksDbWeight::get().reads(15693_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Rbac AccountAttributes (r:1 w:0)
+	/// Storage: System Account (r:1 w:0)
+	/// The range of component `n` is `[1, 16]`.
+	fn evaluate_condition(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 5214)
+			.saturating_add(Weight::from_parts(1_450_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:0)
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	fn take_snapshot() -> Weight {
+		Weight::from_parts(13_000_000, 2590)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	fn remove_snapshot() -> Weight {
+		Weight::from_parts(11_000_000, 2590)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: Rbac PendingRollback (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:1)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:0)
+	fn rollback_to() -> Weight {
+		Weight::from_parts(15_000_000, 2590)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac ExpiryCursor (r:1 w:1)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac PendingRollback (r:1 w:1)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:65 w:65)
+	/// Storage: Rbac RolePermissions (r:0 w:65)
+	/// Storage: Rbac PermissionConstraints (r:0 w:65)
+	/// Storage: Rbac PermissionConditions (r:0 w:65)
+	/// The range of component `c` is `[0, 65]`.
+	fn undo_policy_version(c: u32) -> Weight {
+		Weight::from_parts(16_000_000, 3481)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 6304).saturating_mul(c.into()))
+	}
+	/// Storage: Rbac NextTemplateId (r:1 w:1)
+	/// Storage: Rbac Templates (r:0 w:1)
+	fn create_template() -> Weight {
+		Weight::from_parts(14_500_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn update_template() -> Weight {
+		Weight::from_parts(17_000_000, 3756)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn remove_template() -> Weight {
+		Weight::from_parts(15_500_000, 3756)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Templates (r:1 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:33)
+	/// Storage: Rbac Roles (r:0 w:1)
+	/// Storage: Rbac RolePermissions (r:0 w:32)
+	/// Storage: Rbac RoleTemplates (r:0 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:65 w:65)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn instantiate_template(p: u32) -> Weight {
+		Weight::from_parts(45_000_000, 3756)
+			.saturating_add(Weight::from_parts(10_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5094).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7313 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac NextRoleId (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:33)
+	/// Storage: Rbac RolePermissions (r:32 w:32)
+	/// Storage: Rbac PermissionConstraints (r:32 w:32)
+	/// Storage: Rbac PermissionConditions (r:32 w:32)
+	/// Storage: Rbac RoleDenyRules (r:1 w:0)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:1 w:1)
+	/// Storage: Rbac OwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:129 w:129)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn clone_role(p: u32) -> Weight {
+		Weight::from_parts(32_055_500_000, 564583)
+			.saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(15698_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 10188).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:65)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:0)
+	/// Storage: Rbac RolePermissions (r:64 w:64)
+	/// Storage: Rbac PermissionConstraints (r:0 w:32)
+	/// Storage: Rbac PermissionConditions (r:0 w:32)
+	/// Storage: Rbac RoleDenyRules (r:1 w:0)
+	/// Storage: Rbac Deposits (r:96 w:96)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn sync_role(p: u32) -> Weight {
+		Weight::from_parts(32_049_000_000, 563756)
+			.saturating_add(Weight::from_parts(22_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(15692_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7641).saturating_mul(p.into()))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac RoleTemplates (r:1 w:1)
+	/// Storage: Rbac Templates (r:1 w:1)
+	fn untrack_template() -> Weight {
+		Weight::from_parts(32_021_500_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15685_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac RoleOwners (r:1 w:0)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac PendingOwners (r:1 w:1)
+	/// Storage: Rbac PendingOwnedRoles (r:9 w:2)
+	fn propose_owner() -> Weight {
+		Weight::from_parts(32_039_500_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15694_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac PendingOwners (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac RoleOwners (r:1 w:1)
+	/// Storage: Rbac OwnedRoleCount (r:2 w:2)
+	/// Storage: Rbac OwnedRoles (r:0 w:2)
+	/// Storage: Rbac PendingOwnedRoles (r:0 w:1)
+	/// Storage: Rbac Deposits (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(54_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac FrozenRoles (r:1 w:1)
+	fn freeze_role() -> Weight {
+		Weight::from_parts(13_500_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac FrozenRoles (r:1 w:1)
+	fn thaw_role() -> Weight {
+		Weight::from_parts(10_500_000, 3481)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Enforcement (r:0 w:1)
+	fn set_enforcement_mode() -> Weight {
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BreakGlassRoles (r:0 w:1)
+	fn set_break_glass_role() -> Weight {
+		Weight::from_parts(15_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Rbac BreakGlassRoles (r:1 w:0)
+	/// Storage: Rbac UnreviewedBreakGlass (r:1 w:1)
+	/// Storage: Rbac AccountRoles (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac DelegationsTo (r:1 w:0)
+	/// Storage: Rbac RoleJuniors (r:1 w:0)
+	/// Storage: Rbac RoleConstraints (r:1 w:0)
+	/// Storage: Rbac SessionRoles (r:1 w:1)
+	/// Storage: Rbac NextBreakGlassId (r:1 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	/// Storage: Rbac RoleExpiries (r:0 w:1)
+	/// Storage: Rbac BreakGlassUses (r:0 w:1)
+	fn break_glass() -> Weight {
+		Weight::from_parts(42_500_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Rbac BreakGlassUses (r:1 w:1)
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac UnreviewedBreakGlass (r:0 w:1)
+	fn review_break_glass() -> Weight {
+		Weight::from_parts(32_024_000_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15684_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac PendingRollback (r:1 w:0)
+	/// Storage: Rbac CurrentPolicyVersion (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:1)
+	/// Storage: Rbac Snapshots (r:1 w:0)
+	/// Storage: Rbac PolicyJournalLength (r:1 w:1)
+	/// Storage: Rbac PolicyJournal (r:0 w:1)
+	/// Storage: Rbac Roles (r:2 w:0)
+	/// Storage: Rbac RoleSeniors (r:1 w:0)
+	fn set_grant_approval() -> Weight {
+		Weight::from_parts(29_500_000, 9225)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Rbac Roles (r:1 w:0)
+	/// Storage: Rbac BootstrapAdmin (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac NextGrantProposalId (r:1 w:1)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	/// Storage: Rbac GrantProposals (r:0 w:1)
+	fn propose_grant() -> Weight {
+		Weight::from_parts(32_031_000_000, 563858)
+			.saturating_add(RocksDbWeight::get().reads(15685_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:17 w:1)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac Roles (r:1 w:1)
+	/// Storage: Rbac AccountRoleCount (r:1 w:1)
+	/// Storage: Rbac RoleConstraints (r:1 w:0)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	/// Storage: Rbac RoleMembers (r:0 w:1)
+	fn approve_grant() -> Weight {
+		Weight::from_parts(32_052_000_000, 564067)
+			.saturating_add(RocksDbWeight::get().reads(15687_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantApprovals (r:1 w:0)
+	/// Storage: Rbac AccountRoles (r:16 w:0)
+	/// Storage: Rbac SessionRoles (r:17 w:0)
+	/// Storage: Rbac FrozenRoles (r:7312 w:0)
+	/// Storage: Rbac RoleJuniors (r:8336 w:0)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	fn reject_grant() -> Weight {
+		Weight::from_parts(32_026_000_000, 564067)
+			.saturating_add(RocksDbWeight::get().reads(15683_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	/// Storage: Rbac GrantProposalExpiries (r:0 w:1)
+	fn cancel_grant() -> Weight {
+		Weight::from_parts(16_500_000, 4067)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Rbac GrantProposalExpiries (r:1 w:1)
+	/// Storage: Rbac GrantProposals (r:1 w:1)
+	fn expire_grant_proposal() -> Weight {
+		Weight::from_parts(18_000_000, 4067)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -6,17 +6,28 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{
+		fungible,
+		tokens::{Fortitude, Preservation},
+		Contains,
+	},
+	BoundedVec,
+};
 use pallet_grandpa::AuthorityId as GrandpaId;
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One,
+		StaticLookup, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, RuntimeDebug,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -46,7 +57,7 @@ use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
-/// Import the template pallet.
+/// Import the RBAC pallet.
 pub use pallet_template;
 
 /// An index to a block.
@@ -104,10 +115,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -190,7 +201,7 @@ impl frame_system::Config for Runtime {
 	/// What to do if a new account is created.
 	type OnNewAccount = ();
 	/// What to do if an account is fully reaped from the system.
-	type OnKilledAccount = ();
+	type OnKilledAccount = Rbac;
 	/// The data to be stored in an account.
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// Weight information for the extrinsics of this pallet.
@@ -245,8 +256,8 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
-	type MaxHolds = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<1>;
 }
 
 parameter_types! {
@@ -268,10 +279,128 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
-/// Configure the pallet-template in pallets/template.
+/// Constraints on call arguments that permissions of the RBAC pallet may carry.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CallConstraint {
+	/// Balance transfers of at most this value.
+	MaxTransferValue(Balance),
+	/// Balance transfers to one of these accounts.
+	TransferDestIn(BoundedVec<AccountId, ConstU32<16>>),
+}
+
+/// The destination and value of `call` if it transfers a given value of the native currency.
+///
+/// Every dispatchable transfer of a given value is covered, so that constraints on transfers cannot
+/// be bypassed through another one.
+// `transfer` is deprecated but still dispatchable.
+#[allow(deprecated)]
+fn balance_transfer(call: &RuntimeCall) -> Option<(&Address, Balance)> {
+	match call {
+		RuntimeCall::Balances(
+			BalancesCall::transfer_allow_death { dest, value } |
+			BalancesCall::transfer_keep_alive { dest, value } |
+			BalancesCall::transfer { dest, value },
+		) => Some((dest, *value)),
+		_ => None,
+	}
+}
+
+/// Evaluates [`CallConstraint`]s against the calls of the runtime.
+pub struct CallConstraintEvaluator;
+
+impl pallet_template::ConstraintEvaluator<RuntimeCall> for CallConstraintEvaluator {
+	type Constraint = CallConstraint;
+
+	fn evaluate(constraint: &CallConstraint, call: &RuntimeCall) -> bool {
+		let Some((dest, value)) = balance_transfer(call) else { return false };
+		match constraint {
+			CallConstraint::MaxTransferValue(max) => value <= *max,
+			CallConstraint::TransferDestIn(allowed) =>
+				<Runtime as frame_system::Config>::Lookup::lookup(dest.clone())
+					.map_or(false, |dest| allowed.contains(&dest)),
+		}
+	}
+}
+
+/// Measures the value balance transfers spend, to charge it to RBAC role allowances.
+pub struct TransferredValue;
+
+impl pallet_template::SpendOf<AccountId, RuntimeCall, Balance> for TransferredValue {
+	fn spend_of(who: &AccountId, call: &RuntimeCall) -> Option<Balance> {
+		if let Some((_, value)) = balance_transfer(call) {
+			return Some(value)
+		}
+		match call {
+			RuntimeCall::Balances(BalancesCall::transfer_all { keep_alive, .. }) => {
+				let preservation =
+					if *keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+				Some(<Balances as fungible::Inspect<_>>::reducible_balance(
+					who,
+					preservation,
+					Fortitude::Polite,
+				))
+			},
+			_ => None,
+		}
+	}
+}
+
+/// Calls the RBAC pallet never checks access to.
+///
+/// Sudo authorizes its own origin, and must stay usable to recover from a policy locking
+/// everyone out.
+pub struct RbacExemptCalls;
+
+impl Contains<RuntimeCall> for RbacExemptCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::Sudo(_))
+	}
+}
+
+parameter_types! {
+	/// Access is not checked until root enables it, so that RBAC can be rolled out on a live
+	/// chain in audit mode first.
+	pub const DefaultEnforcementMode: pallet_template::EnforcementMode =
+		pallet_template::EnforcementMode::Disabled;
+}
+
+/// Configure the RBAC pallet in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type IncidentOrigin = frame_system::EnsureRoot<AccountId>;
+	type DefaultEnforcementMode = DefaultEnforcementMode;
+	type ConstraintEvaluator = CallConstraintEvaluator;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type SpendOf = TransferredValue;
+	type ExemptCalls = RbacExemptCalls;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type MaxNameLength = ConstU32<64>;
+	type MaxMetadataLength = ConstU32<256>;
+	type MaxPermissionsPerRole = ConstU32<32>;
+	type MaxConstraintsPerPermission = ConstU32<4>;
+	type MaxRolesPerAccount = ConstU32<16>;
+	type MaxHierarchyDepth = ConstU32<8>;
+	type MaxJuniorsPerRole = ConstU32<8>;
+	type MaxRolesPerConstraint = ConstU32<8>;
+	type MaxDelegationDepth = ConstU32<4>;
+	type MaxDelegationPeriod = ConstU32<{ 30 * DAYS }>;
+	type MaxDelegationsPerAccount = ConstU32<16>;
+	type MaxAttributeKeyLength = ConstU32<32>;
+	type MaxAttributeValueLength = ConstU32<64>;
+	type MaxAttributesPerAccount = ConstU32<16>;
+	type MaxConditionLength = ConstU32<16>;
+	type MaxSnapshots = ConstU32<16>;
+	type MaxPolicyBatchLength = ConstU32<32>;
+	type RoleDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type PermissionDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type ConstraintDeposit = ConstU128<{ 50 * EXISTENTIAL_DEPOSIT }>;
+	type MaxOwnedRoles = ConstU32<64>;
+	type BreakGlassPeriod = ConstU32<{ 30 * MINUTES }>;
+	type MaxGrantApprovals = ConstU32<16>;
+	type GrantProposalLifetime = ConstU32<{ 7 * DAYS }>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -284,8 +413,8 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
-		// Include the custom logic from the pallet-template in the runtime.
-		TemplateModule: pallet_template,
+		// Role based access control, implemented in pallets/template.
+		Rbac: pallet_template,
 	}
 );
 
@@ -305,6 +434,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::CheckAccess<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -333,7 +463,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
-		[pallet_template, TemplateModule]
+		[pallet_template, Rbac]
 	);
 }
 